@@ -1,3 +1,8 @@
-fn main() -> Result<(), String> {
-    unimplemented!()
+use std::process::ExitCode;
+
+use advent_of_2023::{day10::Sketch, registry::INPUT_DIR};
+use adventofrust::solution;
+
+fn main() -> ExitCode {
+    solution::main::<Sketch>(INPUT_DIR.path(10, "day10"))
 }
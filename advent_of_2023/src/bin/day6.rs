@@ -1,38 +1,8 @@
-use std::{io, ops::Deref};
+use std::process::ExitCode;
 
-fn solutionate<S: Deref<Target = str>, I: IntoIterator<Item = io::Result<S>>>(
-    input: I,
-) -> Result<usize, String> {
-    fn aux(line: &str) -> Option<usize> {
-        let (_, line) = line.split_once(':')?;
-        line.split_whitespace().collect::<String>().parse().ok()
-    }
+use advent_of_2023::{day6::Races, registry::INPUT_DIR};
+use adventofrust::solution;
 
-    let mut input_iter = input.into_iter();
-
-    let time_str = &*input_iter
-        .next()
-        .ok_or("Missing input for time.")?
-        .map_err(|e| e.to_string())?;
-    let time =
-        aux(time_str).ok_or_else(|| format!("Failed to parse input for time: {time_str}"))?;
-
-    let distance_str = &*input_iter
-        .next()
-        .ok_or("Missing input for distance.")?
-        .map_err(|e| e.to_string())?;
-    let distance = aux(distance_str)
-        .ok_or_else(|| format!("Failed to parse input for time: {distance_str}"))?;
-
-    let answer = (1..time - 1)
-        .filter(|charging_time| charging_time * (time - charging_time) > distance)
-        .count();
-
-    Ok(answer)
-}
-
-fn main() -> Result<(), String> {
-    let answer = solutionate(io::stdin().lines())?;
-    println!("{}", answer);
-    Ok(())
+fn main() -> ExitCode {
+    solution::main::<Races>(INPUT_DIR.path(6, "day6"))
 }
@@ -0,0 +1,88 @@
+//! Day 1: Trebuchet?!
+//! https://adventofcode.com/2023/day/1
+
+use adventofrust::{
+    error::{self, Error},
+    solution::Solution,
+};
+
+const DIGIT_WORDS: [(&str, usize); 10] = [
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Returns the digit the string starts with, if any. Digits spelled out with letters only count
+/// when `spelled` is set.
+fn leading_digit(s: &str, spelled: bool) -> Option<usize> {
+    if let Some(d) = s.chars().next()?.to_digit(10) {
+        // If there's at least one character in the string and the first one is a digit,
+        // then return that digit as usize.
+        Some(d as usize)
+    } else if spelled {
+        // Otherwise, go through `DIGIT_WORDS` and try to match a word to the start of the
+        // string; if there is a match, then return the corresponding digit; otherwise, it's not
+        // a digit so return `None`.
+        DIGIT_WORDS
+            .iter()
+            .find(|(key, _)| s.starts_with(key))
+            .map(|&(_, value)| value)
+    } else {
+        None
+    }
+}
+
+fn calibration_value(line: &str, spelled: bool) -> Result<usize, Error> {
+    let mut iter = (0..line.len())
+        .map(|i| &line[i..])
+        .filter_map(|s| leading_digit(s, spelled));
+    // Problem description doesn't specify what to do if the input has no digit, so assume
+    // the input is invalid.
+    let first_digit = iter
+        .next()
+        .ok_or_else(|| Error::new(format!("No digit found in line: {}", line)))?;
+    // Problem description does specify that if the second digit is missing, the first
+    // digit is used twice.
+    let last_digit = iter.next_back().unwrap_or(first_digit);
+
+    Ok(first_digit * 10 + last_digit)
+}
+
+pub struct CalibrationDocument(Vec<String>);
+
+impl CalibrationDocument {
+    fn sum(&self, spelled: bool) -> Result<usize, Error> {
+        self.0
+            .iter()
+            .map(|line| calibration_value(line, spelled))
+            .sum()
+    }
+}
+
+impl Solution for CalibrationDocument {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(|line| error::ensure_ascii(line).map(|()| line.to_owned()))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        self.sum(false)
+    }
+
+    fn part2(&self) -> Result<usize, Error> {
+        self.sum(true)
+    }
+}
@@ -0,0 +1,27 @@
+//! Day 10: Pipe Maze
+//! https://adventofcode.com/2023/day/10
+
+#![allow(dead_code)]
+use adventofrust::{
+    error::Error,
+    solution::{unsolved, Solution, Unsolved},
+};
+
+pub struct Sketch(Vec<String>);
+
+impl Solution for Sketch {
+    type Answer1 = Unsolved;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self(input.lines().map(str::to_owned).collect()))
+    }
+
+    fn part1(&self) -> Result<Unsolved, Error> {
+        unsolved()
+    }
+
+    fn part2(&self) -> Result<Unsolved, Error> {
+        unsolved()
+    }
+}
@@ -0,0 +1,135 @@
+//! Day 2: Cube Conundrum
+//! https://adventofcode.com/2023/day/2
+
+use adventofrust::{error::Error, solution::Solution};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Color {
+    Blue,
+    Red,
+    Green,
+}
+
+impl FromStr for Color {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("blue") {
+            Ok(Self::Blue)
+        } else if s.eq_ignore_ascii_case("red") {
+            Ok(Self::Red)
+        } else if s.eq_ignore_ascii_case("green") {
+            Ok(Self::Green)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CubeCounts {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+impl CubeCounts {
+    pub fn acc(self, other: Self) -> Self {
+        Self {
+            red: self.red.max(other.red),
+            green: self.green.max(other.green),
+            blue: self.blue.max(other.blue),
+        }
+    }
+
+    pub fn power(&self) -> usize {
+        self.red * self.green * self.blue
+    }
+}
+
+impl FromStr for CubeCounts {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cube_counts = CubeCounts::default();
+
+        for item in s.split(',') {
+            let item = item.trim();
+            let (count_str, color_str) = item
+                .split_once(' ')
+                .ok_or_else(|| Error::at(item, "Expected a count and a color"))?;
+            let count = count_str
+                .parse()
+                .map_err(|e| Error::at(count_str, format!("Invalid count: {e}")))?;
+            match color_str
+                .parse()
+                .map_err(|_| Error::at(color_str, "Expected red, green or blue"))?
+            {
+                Color::Blue => cube_counts.blue = count,
+                Color::Red => cube_counts.red = count,
+                Color::Green => cube_counts.green = count,
+            }
+        }
+
+        Ok(cube_counts)
+    }
+}
+
+impl CubeCounts {
+    pub fn fits_in(&self, bag: &Self) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+}
+
+/// Every handful of cubes revealed in each game; games are identified by line number.
+pub struct Record(Vec<Vec<CubeCounts>>);
+
+impl Solution for Record {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(|s| -> Result<Vec<CubeCounts>, Error> {
+                // Trim useless ID information. We just use line number instead.
+                let (_, s) = s
+                    .split_once(':')
+                    .ok_or_else(|| Error::at(s, "Missing `:` after the game ID"))?;
+
+                s.split(';').map(|x| x.trim().parse()).collect()
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        const BAG: CubeCounts = CubeCounts {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        Ok(self
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, game)| game.iter().all(|x| x.fits_in(&BAG)))
+            .map(|(i, _)| i + 1)
+            .sum())
+    }
+
+    fn part2(&self) -> Result<usize, Error> {
+        self.0
+            .iter()
+            .map(|game| {
+                game.iter()
+                    .copied()
+                    .reduce(CubeCounts::acc)
+                    .map(|x| x.power())
+                    .ok_or_else(|| Error::new("Input is empty"))
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,192 @@
+//! Day 3: Gear Ratios
+//! https://adventofcode.com/2023/day/3
+
+#![allow(unused)]
+use std::mem::swap;
+
+use adventofrust::{
+    error::{self, Error},
+    solution::Solution,
+};
+
+trait ToDigit {
+    fn to_digit(self) -> Option<usize>;
+}
+
+impl ToDigit for u8 {
+    fn to_digit(self) -> Option<usize> {
+        // Using lazy evaluation here because some characters' byte values are less than the byte
+        // value of the character '0', then `self - b'0'` can underflow. Of course, I can use
+        // `saturating_sub` or `checked_sub` instead, but more exploration needed to figure out
+        // which is actually better.
+        #[allow(clippy::unnecessary_lazy_evaluations)]
+        self.is_ascii_digit().then(|| (self - b'0') as usize)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PartNumber {
+    start_index: usize,
+    end_index: usize,
+    value: usize,
+}
+
+impl PartNumber {
+    fn new(start_index: usize, end_index: usize, value: usize) -> Self {
+        Self {
+            start_index: start_index.saturating_sub(1),
+            end_index: end_index + 1,
+            value,
+        }
+    }
+
+    pub fn check(&self, i: usize) -> bool {
+        (self.start_index..=self.end_index).contains(&i)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ScanningWindow<T> {
+    v: [Vec<T>; 3],
+}
+
+impl<T> Default for ScanningWindow<T> {
+    fn default() -> Self {
+        Self {
+            v: [Vec::default(), Vec::default(), Vec::default()],
+        }
+    }
+}
+
+impl<T> ScanningWindow<T> {
+    fn push(&mut self, value: T) {
+        self.v[2].push(value);
+    }
+
+    fn newline(&mut self) {
+        self.v.swap(0, 1);
+        self.v.swap(1, 2);
+        self.v[2].clear();
+    }
+
+    pub fn middle(&self) -> &Vec<T> {
+        &self.v[1]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.v.iter().flatten()
+    }
+}
+
+/// Streams the schematic through a window of three rows, calling `f` once for every row after
+/// the rows on either side of it have been scanned; that row is then the middle of the windows.
+/// Symbols are stored with their index in the row.
+pub fn scan<'a>(
+    lines: impl Iterator<Item = &'a str>,
+    mut f: impl FnMut(&ScanningWindow<PartNumber>, &ScanningWindow<(usize, u8)>),
+) {
+    let mut part_numbers = ScanningWindow::default();
+    let mut symbols = ScanningWindow::default();
+
+    let mut process = |s: &str| {
+        let mut started = false;
+        let mut value = 0;
+        let mut start_index = 0;
+        let mut end_index = 0;
+
+        for (i, c) in s.bytes().enumerate() {
+            if let Some(d) = c.to_digit() {
+                if started {
+                    // Accumulating.
+                    value *= 10;
+                    value += d;
+                } else {
+                    // First digit.
+                    value += d;
+                    start_index = i;
+                    started = true;
+                }
+            } else {
+                if c != b'.' {
+                    symbols.push((i, c));
+                }
+
+                if started {
+                    part_numbers.push(PartNumber::new(start_index, end_index, value));
+                    started = false;
+                    value = 0;
+                }
+            }
+
+            end_index = i;
+        }
+
+        if started {
+            part_numbers.push(PartNumber::new(start_index, end_index, value));
+        }
+
+        f(&part_numbers, &symbols);
+
+        symbols.newline();
+        part_numbers.newline();
+    };
+
+    for line in lines {
+        process(line);
+    }
+
+    process("");
+}
+
+pub struct Schematic(Vec<String>);
+
+impl Solution for Schematic {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(|s| error::ensure_ascii(s).map(|()| s.to_owned()))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        let mut sum = 0;
+
+        scan(
+            self.0.iter().map(String::as_str),
+            |part_numbers, symbols| {
+                for p in part_numbers.middle() {
+                    if symbols.iter().any(|&(i, _)| p.check(i)) {
+                        sum += p.value;
+                    }
+                }
+            },
+        );
+
+        Ok(sum)
+    }
+
+    fn part2(&self) -> Result<usize, Error> {
+        let mut sum = 0;
+
+        scan(
+            self.0.iter().map(String::as_str),
+            |part_numbers, symbols| {
+                // I wonder if multiple gears are allowed to share numbers. This is not specified.
+                for &(i, _) in symbols.middle().iter().filter(|&&(_, c)| c == b'*') {
+                    // Create a filter iterator that emits part numbers adjacent to a gear symbol.
+                    let mut iter = part_numbers.iter().filter(|&p| p.check(i));
+                    // Use pattern matching to check that the iterator yields exactly two items.
+                    if let (Some(a), Some(b), None) = (iter.next(), iter.next(), iter.next()) {
+                        sum += a.value * b.value;
+                    }
+                }
+            },
+        );
+
+        Ok(sum)
+    }
+}
@@ -0,0 +1,528 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//! https://adventofcode.com/2023/day/5
+
+use std::{
+    cmp::Reverse,
+    iter::from_fn,
+    ops::{Add, Deref, Range},
+    str::FromStr,
+};
+
+use adventofrust::{error::Error, solution::Solution};
+
+// Helper extension traits{{{
+pub trait RangeUniformAdd<T> {
+    fn uniform_add(self, v: T) -> Self;
+}
+
+impl<T: Add<Output = T> + Copy> RangeUniformAdd<T> for Range<T> {
+    fn uniform_add(self, v: T) -> Self {
+        (self.start + v)..(self.end + v)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubtractionResult<T> {
+    None,
+    Full,
+    Leftover0 {
+        subtracted: T,
+        leftover: T,
+    },
+    Leftover1 {
+        subtracted: T,
+        leftover0: T,
+        leftover1: T,
+    },
+}
+
+pub trait SubtractRange {
+    fn subtract_range(&self, other: &Self) -> SubtractionResult<Self>
+    where
+        Self: Sized;
+}
+
+impl<T: Ord + Copy> SubtractRange for Range<T> {
+    fn subtract_range(&self, other: &Self) -> SubtractionResult<Self> {
+        if self.end <= other.start || other.end <= self.start {
+            SubtractionResult::<Self>::None
+        } else if other.start <= self.start {
+            if self.end <= other.end {
+                SubtractionResult::<Self>::Full
+            } else {
+                SubtractionResult::<Self>::Leftover0 {
+                    subtracted: self.start..other.end,
+                    leftover: other.end..self.end,
+                }
+            }
+        } else if self.end <= other.end {
+            SubtractionResult::<Self>::Leftover0 {
+                subtracted: other.start..self.end,
+                leftover: self.start..other.start,
+            }
+        } else {
+            SubtractionResult::<Self>::Leftover1 {
+                subtracted: other.start..other.end,
+                leftover0: self.start..other.start,
+                leftover1: other.end..self.end,
+            }
+        }
+    }
+}
+
+pub trait DefragmentRanges {
+    fn defragment_ranges(&mut self);
+}
+
+impl<T: Ord + Copy> DefragmentRanges for Vec<Range<T>> {
+    fn defragment_ranges(&mut self) {
+        self.sort_unstable_by_key(|x| Reverse(x.start));
+        for i in (0..self.len() - 1).rev() {
+            if self[i + 1].end >= self[i].start {
+                self[i].start = self.swap_remove(i + 1).start;
+            }
+        }
+    }
+} //}}}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Converter {
+    //{{{
+    source_range: Range<isize>,
+    offset: isize,
+}
+
+impl Converter {
+    pub fn from_str_opt(s: &str) -> Option<Self> {
+        let mut iter = s.split_whitespace();
+
+        let dest_start: isize = iter.next()?.parse().ok()?;
+        let source_start: isize = iter.next()?.parse().ok()?;
+        let len: isize = iter.next()?.parse().ok()?;
+
+        if iter.next().is_some() {
+            return None;
+        }
+
+        Some(Converter {
+            offset: dest_start - source_start,
+            source_range: source_start..source_start + len,
+        })
+    }
+
+    pub fn process_ranges(&self, ranges: &mut Vec<Range<isize>>, out: &mut Vec<Range<isize>>) {
+        for i in (0..ranges.len()).rev() {
+            match ranges[i].subtract_range(&self.source_range) {
+                SubtractionResult::None => (),
+                SubtractionResult::Full => out.push(ranges.swap_remove(i).uniform_add(self.offset)),
+                SubtractionResult::Leftover0 {
+                    subtracted,
+                    leftover,
+                } => {
+                    ranges[i] = leftover;
+                    out.push(subtracted.uniform_add(self.offset));
+                }
+                SubtractionResult::Leftover1 {
+                    subtracted,
+                    leftover0,
+                    leftover1,
+                } => {
+                    ranges[i] = leftover0;
+                    ranges.push(leftover1);
+                    out.push(subtracted.uniform_add(self.offset));
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Converter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut iter = s.split_whitespace();
+        let mut field = |name: &str| -> Result<isize, Error> {
+            let field = iter
+                .next()
+                .ok_or_else(|| Error::at(&s[s.len()..], format!("Missing {name}")))?;
+            field
+                .parse()
+                .map_err(|e| Error::at(field, format!("Invalid {name}: {e}")))
+        };
+
+        let dest_start = field("destination range start")?;
+        let source_start = field("source range start")?;
+        let len = field("range length")?;
+
+        if let Some(extra) = iter.next() {
+            return Err(Error::at(extra, "Expected only three numbers"));
+        }
+
+        Ok(Converter {
+            offset: dest_start - source_start,
+            source_range: source_start..source_start + len,
+        })
+    }
+    //}}}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapper(Vec<Converter>); //{{{
+
+impl Mapper {
+    pub fn from_str_iter<S: Deref<Target = str>, T: IntoIterator<Item = S>>(
+        iter: T,
+    ) -> Option<Self> {
+        let mut iter = iter.into_iter();
+        let mut started = false;
+
+        // let first_line = iter.next().unwrap();
+        // let title = first_line.trim().strip_suffix(" map:");
+        // let _ = iter.next()?; // Skip first line
+
+        // Use a closure that captures the input `iter` to create a custom iterator which
+        // skips any number of lines before thte first converter is successfully parsed, then
+        // terminates either at the end of the input or after the first str that fails to parse
+        // into a `Converter`.
+        let converters_iter = from_fn(|| {
+            if started {
+                Converter::from_str_opt(&iter.next()?)
+            } else {
+                while let Some(item) = &iter.next() {
+                    let converter_opt = Converter::from_str_opt(item);
+                    if converter_opt.is_some() {
+                        started = true;
+                        return converter_opt;
+                    }
+                }
+
+                None
+            }
+        });
+
+        let v: Vec<Converter> = converters_iter.collect();
+        (!v.is_empty()).then_some(Self(v))
+    }
+
+    pub fn process_ranges(&self, ranges: &mut Vec<Range<isize>>, out: &mut Vec<Range<isize>>) {
+        for converter in &self.0 {
+            converter.process_ranges(ranges, out);
+        }
+    }
+}
+
+/// Parses one block of the almanac: a `X-to-Y map:` header followed by one converter per line.
+impl FromStr for Mapper {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let title = lines.next().unwrap_or(s);
+        if !title.trim_end().ends_with(" map:") {
+            return Err(Error::at(title, "Expected a `X-to-Y map:` header"));
+        }
+
+        let converters = lines.map(str::parse).collect::<Result<Vec<_>, _>>()?;
+        if converters.is_empty() {
+            return Err(Error::at(title, "Map has no ranges"));
+        }
+
+        Ok(Self(converters))
+    }
+} //}}}
+
+pub struct Almanac {
+    seeds: Vec<isize>,
+    mappers: Vec<Mapper>,
+}
+
+impl Almanac {
+    pub fn lowest_location(&self, mut seed_ranges: Vec<Range<isize>>) -> Result<isize, Error> {
+        if seed_ranges.is_empty() {
+            return Err(Error::new("No seeds found"));
+        }
+
+        let mut staging = Vec::new();
+        for mapper in &self.mappers {
+            mapper.process_ranges(&mut seed_ranges, &mut staging);
+
+            seed_ranges.append(&mut staging);
+            seed_ranges.defragment_ranges();
+        }
+
+        Ok(seed_ranges
+            .into_iter()
+            .map(|x| x.start)
+            .min()
+            .expect("`seed_ranges` is not empty"))
+    }
+}
+
+impl Solution for Almanac {
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        // Blocks are separated by blank lines: the seeds first, then one block per map.
+        let mut blocks = input
+            .split("\n\n")
+            .map(|block| block.trim_matches('\n'))
+            .filter(|block| !block.is_empty());
+
+        let first_line = blocks
+            .next()
+            .ok_or_else(|| Error::at(input, "Empty input"))?;
+        let seeds = first_line
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| Error::at(first_line, "Expected `seeds: ` on the first line"))?
+            .split_whitespace()
+            .map(|seed| {
+                seed.parse()
+                    .map_err(|e| Error::at(seed, format!("Invalid seed: {e}")))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mappers = blocks.map(str::parse).collect::<Result<_, _>>()?;
+
+        Ok(Self { seeds, mappers })
+    }
+
+    fn part1(&self) -> Result<isize, Error> {
+        self.lowest_location(self.seeds.iter().map(|&seed| seed..seed + 1).collect())
+    }
+
+    /// The seeds line actually lists ranges of seeds, as pairs of start and length.
+    fn part2(&self) -> Result<isize, Error> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(Error::new("Seed ranges are not given in pairs"));
+        }
+
+        self.lowest_location(
+            self.seeds
+                .chunks(2)
+                .map(|pair| pair[0]..pair[0] + pair[1])
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //{{{
+    use super::*;
+
+    #[test]
+    fn test_subtract_range_0() {
+        assert_eq!(SubtractionResult::None, (0..10).subtract_range(&(20..30)))
+    }
+    #[test]
+    fn test_subtract_range_1() {
+        assert_eq!(SubtractionResult::None, (50..100).subtract_range(&(20..30)))
+    }
+    #[test]
+    fn test_subtract_range_2() {
+        assert_eq!(SubtractionResult::Full, (70..75).subtract_range(&(50..100)))
+    }
+    #[test]
+    fn test_subtract_range_3() {
+        assert_eq!(
+            SubtractionResult::Leftover1 {
+                subtracted: 70..75,
+                leftover0: 50..70,
+                leftover1: 75..100
+            },
+            (50..100).subtract_range(&(70..75))
+        )
+    }
+    #[test]
+    fn test_subtract_range_4() {
+        assert_eq!(
+            SubtractionResult::Leftover0 {
+                subtracted: 50..75,
+                leftover: 75..100
+            },
+            (50..100).subtract_range(&(50..75))
+        )
+    }
+    #[test]
+    fn test_subtract_range_5() {
+        assert_eq!(
+            SubtractionResult::Leftover0 {
+                subtracted: 90..100,
+                leftover: 50..90
+            },
+            (50..100).subtract_range(&(90..1000))
+        )
+    }
+    #[test]
+    fn test_subtract_range_6() {
+        assert_eq!(
+            SubtractionResult::Leftover0 {
+                subtracted: 79..80,
+                leftover: 80..93
+            },
+            (79..93).subtract_range(&(60..80))
+        )
+    }
+    #[test]
+    fn test_subtract_range_7() {
+        assert_eq!(
+            SubtractionResult::Leftover0 {
+                subtracted: 60..68,
+                leftover: 55..60
+            },
+            (55..68).subtract_range(&(60..80))
+        )
+    }
+
+    #[test]
+    fn test_converter_process_ranges_0() {
+        let converter = Converter {
+            source_range: 60..80,
+            offset: 100,
+        };
+
+        let mut input = vec![79..93, 55..68];
+        let mut out = Vec::new();
+        converter.process_ranges(&mut input, &mut out);
+
+        assert!(input.contains(&(80..93)));
+        assert!(input.contains(&(55..60)));
+        assert!(out.contains(&(179..180)));
+        assert!(out.contains(&(160..168)));
+    }
+
+    #[test]
+    fn test_mapper_from_str_iter_0() {
+        let s = "";
+        assert_eq!(None, Mapper::from_str_iter(s.lines()));
+    }
+
+    #[test]
+    fn test_mapper_from_str_iter_1() {
+        let s = "seed-to-soil map:";
+        assert_eq!(None, Mapper::from_str_iter(s.lines()));
+    }
+
+    #[test]
+    fn test_mapper_from_str_iter_2() {
+        let s = "
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:";
+        let mut iter = s.lines();
+        let mapper = Mapper::from_str_iter(&mut iter).unwrap();
+        assert_eq!(
+            vec![
+                Converter {
+                    source_range: 98..100,
+                    offset: -48
+                },
+                Converter {
+                    source_range: 50..98,
+                    offset: 2
+                }
+            ],
+            mapper.0
+        );
+        assert_eq!(Some("soil-to-fertilizer map:"), iter.next());
+    }
+
+    // #[test]
+    // fn test_mapper_map_0() {
+    //     let mut ranges = vec![79..93, 55..68];
+    //     let mut out = Vec::new();
+    //     let mapper = Mapper(vec![
+    //         Converter {
+    //             source_range: 98..100,
+    //             offset: -48,
+    //         },
+    //         Converter {
+    //             source_range: 50..98,
+    //             offset: 2,
+    //         },
+    //     ]);
+    //     mapper.process_ranges(&mut ranges, &mut out);
+    // }
+
+    #[test]
+    fn test_solution_0() {
+        let s = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let almanac = Almanac::parse(s).unwrap();
+        assert_eq!(Ok(35), almanac.part1());
+        assert_eq!(Ok(46), almanac.part2());
+    }
+
+    #[test]
+    fn test_mapper_from_str() {
+        let mapper: Mapper = "seed-to-soil map:\n50 98 2\n52 50 48".parse().unwrap();
+        assert_eq!(
+            vec![
+                Converter {
+                    source_range: 98..100,
+                    offset: -48
+                },
+                Converter {
+                    source_range: 50..98,
+                    offset: 2
+                }
+            ],
+            mapper.0
+        );
+        assert!("seed-to-soil map:".parse::<Mapper>().is_err());
+        assert!("50 98 2\n52 50 48".parse::<Mapper>().is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let location = |s: &str| {
+            let error = Almanac::parse(s).err().unwrap().locate(s);
+            error.location().map(|l| (l.line, l.column, l.len))
+        };
+
+        assert_eq!(Some((1, 11, 3)), location("seeds: 79 1x4"));
+        assert_eq!(
+            Some((4, 4, 2)),
+            location("seeds: 79 14\n\nseed-to-soil map:\n50 9x 2")
+        );
+        assert_eq!(
+            Some((4, 9, 1)),
+            location("seeds: 79 14\n\nseed-to-soil map:\n50 98 2 7")
+        );
+    }
+} //}}}
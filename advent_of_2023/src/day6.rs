@@ -0,0 +1,77 @@
+//! Day 6: Wait For It
+//! https://adventofcode.com/2023/day/6
+
+use adventofrust::{error::Error, solution::Solution};
+
+/// Number of ways to beat the record `distance` in a race lasting `time`.
+pub fn count_ways(time: usize, distance: usize) -> usize {
+    (1..time)
+        .filter(|charging_time| charging_time * (time - charging_time) > distance)
+        .count()
+}
+
+/// The numbers on the time and distance lines, without the labels.
+pub struct Races {
+    times: String,
+    distances: String,
+}
+
+impl Solution for Races {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        fn aux(line: Option<&str>, end: &str, label: &str) -> Result<String, Error> {
+            let line = line.ok_or_else(|| Error::at(end, format!("Missing input for {label}.")))?;
+            let (_, numbers) = line
+                .split_once(':')
+                .ok_or_else(|| Error::at(line, format!("Missing `:` after the {label} label")))?;
+
+            for x in numbers.split_whitespace() {
+                x.parse::<usize>()
+                    .map_err(|e| Error::at(x, format!("Invalid {label}: {e}")))?;
+            }
+
+            Ok(numbers.to_owned())
+        }
+
+        let mut input_iter = input.lines();
+        let end = &input[input.len()..];
+        let times = aux(input_iter.next(), end, "time")?;
+        let distances = aux(input_iter.next(), end, "distance")?;
+
+        Ok(Self { times, distances })
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        let times = self.times.split_whitespace();
+        let distances = self.distances.split_whitespace();
+
+        if times.clone().count() != distances.clone().count() {
+            return Err(Error::new("Numbers of times and distances differ."));
+        }
+
+        Ok(times
+            .zip(distances)
+            .map(|(time, distance)| {
+                count_ways(
+                    time.parse().expect("checked when parsing"),
+                    distance.parse().expect("checked when parsing"),
+                )
+            })
+            .product())
+    }
+
+    /// The spaces between the numbers are to be ignored, so there's only one race.
+    fn part2(&self) -> Result<usize, Error> {
+        fn aux(numbers: &str) -> Result<usize, Error> {
+            numbers
+                .split_whitespace()
+                .collect::<String>()
+                .parse()
+                .map_err(|_| Error::new(format!("Failed to parse {numbers} as one number.")))
+        }
+
+        Ok(count_ways(aux(&self.times)?, aux(&self.distances)?))
+    }
+}
@@ -0,0 +1,299 @@
+//! Day 7: Camel Cards
+//! https://adventofcode.com/2023/day/7
+
+use std::{
+    cmp::Ordering,
+    ops::{Index, IndexMut},
+};
+
+use adventofrust::{error::Error, solution::Solution};
+
+/// Which of the two meanings of `J` is in effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rules {
+    /// `J` is a jack, ranked between the ten and the queen.
+    Jacks,
+    /// `J` is a joker, the weakest card, which can pretend to be any other card to make the
+    /// strongest hand.
+    Jokers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Card(u8); //{{{
+
+impl Card {
+    const MEMBERS_WITH_JACKS: [(u8, u8); 13] = [
+        (b'2', 0),
+        (b'3', 1),
+        (b'4', 2),
+        (b'5', 3),
+        (b'6', 4),
+        (b'7', 5),
+        (b'8', 6),
+        (b'9', 7),
+        (b'T', 8),
+        (b'J', 9),
+        (b'Q', 10),
+        (b'K', 11),
+        (b'A', 12),
+    ];
+
+    const MEMBERS_WITH_JOKERS: [(u8, u8); 13] = [
+        (b'J', 0),
+        (b'2', 1),
+        (b'3', 2),
+        (b'4', 3),
+        (b'5', 4),
+        (b'6', 5),
+        (b'7', 6),
+        (b'8', 7),
+        (b'9', 8),
+        (b'T', 9),
+        (b'Q', 10),
+        (b'K', 11),
+        (b'A', 12),
+    ];
+
+    const JOKER: Self = Self(0);
+
+    pub fn with_ascii_char(input: u8, rules: Rules) -> Option<Self> {
+        let members = match rules {
+            Rules::Jacks => Self::MEMBERS_WITH_JACKS,
+            Rules::Jokers => Self::MEMBERS_WITH_JOKERS,
+        };
+        members
+            .into_iter()
+            .find_map(|(c, x)| (input == c).then_some(Self(x)))
+    }
+}
+//}}}
+
+#[derive(Debug, Clone, Copy)]
+struct CardCounts {
+    //{{{
+    max: u8,
+    second_max: u8,
+}
+
+impl CardCounts {
+    fn new() -> Self {
+        Self {
+            max: 0,
+            second_max: 0,
+        }
+    }
+
+    fn register(&mut self, v: u8) {
+        if v > self.max {
+            self.second_max = self.max;
+            self.max = v;
+        } else if v > self.second_max {
+            self.second_max = v;
+        }
+    }
+    //}}}
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct CardCounter([u8; 13]); //{{{
+
+impl CardCounter {
+    fn new() -> Self {
+        CardCounter([0; 13])
+    }
+
+    fn add(&mut self, c: Card) {
+        self[c] += 1;
+    }
+
+    fn get(&self, rules: Rules) -> CardCounts {
+        let mut counts = CardCounts::new();
+        match rules {
+            Rules::Jacks => {
+                for count in self.0 {
+                    counts.register(count);
+                }
+            }
+            Rules::Jokers => {
+                for count in self.0.into_iter().skip(1) {
+                    counts.register(count);
+                }
+                counts.max += self[Card::JOKER];
+            }
+        }
+        counts
+    }
+}
+
+impl Index<Card> for CardCounter {
+    type Output = u8;
+
+    fn index(&self, index: Card) -> &Self::Output {
+        self.0.index(index.0 as usize)
+    }
+}
+
+impl IndexMut<Card> for CardCounter {
+    fn index_mut(&mut self, index: Card) -> &mut Self::Output {
+        self.0.index_mut(index.0 as usize)
+    }
+}
+//}}}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandTier {
+    //{{{
+    Shit,
+    One,
+    Two,
+    Three,
+    House,
+    Four,
+    Five,
+}
+
+impl HandTier {
+    pub fn new(cards: [Card; 5], rules: Rules) -> Self {
+        let card_counts = {
+            let mut card_counter = CardCounter::new();
+            for card in cards {
+                card_counter.add(card);
+            }
+            card_counter.get(rules)
+        };
+
+        if card_counts.max == 5 {
+            Self::Five
+        } else if card_counts.max == 4 {
+            Self::Four
+        } else if card_counts.max == 3 {
+            if card_counts.second_max == 2 {
+                Self::House
+            } else {
+                Self::Three
+            }
+        } else if card_counts.max == 2 {
+            if card_counts.second_max == 2 {
+                Self::Two
+            } else {
+                Self::One
+            }
+        } else {
+            Self::Shit
+        }
+    }
+    //}}}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Hand {
+    //{{{
+    tier: HandTier,
+    bid: usize,
+    cards: [Card; 5],
+}
+
+impl Hand {
+    pub fn with_str(s: &str, rules: Rules) -> Result<Self, Error> {
+        let (cards_str, bid_str) = s
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| Error::at(s, "Expected five cards and a bid"))?;
+
+        if cards_str.chars().count() != 5 {
+            return Err(Error::at(cards_str, "Expected five cards"));
+        }
+
+        let mut cards = [Card::default(); 5];
+        for (card, (i, c)) in cards.iter_mut().zip(cards_str.char_indices()) {
+            *card = u8::try_from(c)
+                .ok()
+                .and_then(|c| Card::with_ascii_char(c, rules))
+                .ok_or_else(|| Error::at(&cards_str[i..i + c.len_utf8()], "Invalid card"))?;
+        }
+
+        Ok(Self {
+            tier: HandTier::new(cards, rules),
+            bid: bid_str
+                .parse()
+                .map_err(|e| Error::at(bid_str, format!("Invalid bid: {e}")))?,
+            cards,
+        })
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cards == other.cards
+    }
+}
+impl Eq for Hand {}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.tier.cmp(&other.tier) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => self
+                .cards
+                .into_iter()
+                .zip(other.cards)
+                .find_map(|(a, b)| match a.cmp(&b) {
+                    Ordering::Less => Some(Ordering::Less),
+                    Ordering::Greater => Some(Ordering::Greater),
+                    Ordering::Equal => None,
+                })
+                .unwrap_or(Ordering::Equal),
+        }
+    }
+    //}}}
+}
+
+pub fn total_winnings(hands: &[Hand]) -> usize {
+    let mut hands = hands.to_vec();
+    hands.sort();
+    hands
+        .into_iter()
+        .enumerate()
+        .map(|(i, hand)| (i + 1) * hand.bid)
+        .sum()
+}
+
+/// Every hand, read under both rules.
+pub struct Game {
+    with_jacks: Vec<Hand>,
+    with_jokers: Vec<Hand>,
+}
+
+impl Solution for Game {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        let parse_hands = |rules| {
+            input
+                .lines()
+                .map(|line| Hand::with_str(line, rules))
+                .collect::<Result<Vec<Hand>, Error>>()
+        };
+
+        Ok(Self {
+            with_jacks: parse_hands(Rules::Jacks)?,
+            with_jokers: parse_hands(Rules::Jokers)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        Ok(total_winnings(&self.with_jacks))
+    }
+
+    fn part2(&self) -> Result<usize, Error> {
+        Ok(total_winnings(&self.with_jokers))
+    }
+}
@@ -0,0 +1,146 @@
+//! Day 8: Haunted Wasteland
+//! https://adventofcode.com/2023/day/8
+
+use std::{collections::HashMap, ops::Index};
+
+use adventofrust::{
+    error::{self, Error},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<u8> for Direction {
+    type Error = ();
+
+    fn try_from(c: u8) -> Result<Self, Self::Error> {
+        if c == b'L' {
+            Ok(Self::Left)
+        } else if c == b'R' {
+            Ok(Self::Right)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<T> Index<Direction> for (T, T) {
+    type Output = T;
+
+    fn index(&self, index: Direction) -> &Self::Output {
+        match index {
+            Direction::Left => &self.0,
+            Direction::Right => &self.1,
+        }
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+pub struct Network {
+    instructions: Vec<Direction>,
+    map: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    /// Number of steps it takes to get from `start` to a node accepted by `is_end`.
+    pub fn count_steps(&self, start: &str, is_end: impl Fn(&str) -> bool) -> Result<usize, Error> {
+        // Past this many steps, we must be going around in circles.
+        let limit = self.instructions.len() * self.map.len();
+
+        let mut node = start;
+        for (step, &dir) in self.instructions.iter().cycle().enumerate() {
+            if is_end(node) {
+                return Ok(step);
+            }
+            if step > limit {
+                break;
+            }
+            node = &self
+                .map
+                .get(node)
+                .ok_or_else(|| Error::new(format!("Requested node doesn't exist: {node}")))?[dir];
+        }
+
+        Err(Error::new(format!(
+            "No end node can be reached from {start}"
+        )))
+    }
+}
+
+impl Solution for Network {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        let mut input = input.lines();
+
+        let line = input.next().ok_or_else(|| Error::new("Missing input"))?;
+
+        error::ensure_ascii(line)?;
+
+        let instructions = line
+            .bytes()
+            .enumerate()
+            .map(|(i, c)| {
+                Direction::try_from(c).map_err(|_| Error::at(&line[i..i + 1], "Expected L or R"))
+            })
+            .collect::<Result<Vec<Direction>, Error>>()?;
+
+        if instructions.is_empty() {
+            return Err(Error::at(line, "Missing instructions on first line."));
+        }
+
+        let map = input
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let aux = || {
+                    let (node_id, nexts) = line.trim().split_once('=')?;
+                    let (next_left, next_right) = nexts
+                        .trim_start()
+                        .strip_prefix('(')?
+                        .strip_suffix(')')?
+                        .split_once(',')?;
+
+                    Some((
+                        node_id.trim_end().to_owned(),
+                        (next_left.trim().to_owned(), next_right.trim().to_owned()),
+                    ))
+                };
+                aux().ok_or_else(|| Error::at(line, "Expected `AAA = (BBB, CCC)`"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { instructions, map })
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        self.count_steps("AAA", |node| node == "ZZZ")
+    }
+
+    /// Walking all the ghosts in lockstep until they all stand on an end node at once takes far
+    /// too long. Instead, this assumes, as holds for the puzzle inputs, that each ghost loops
+    /// back to its first end node every time after the same number of steps it took to first
+    /// reach it, so they all meet at the least common multiple of those numbers.
+    fn part2(&self) -> Result<usize, Error> {
+        self.map
+            .keys()
+            .filter(|s| s.ends_with('A'))
+            .map(|start| self.count_steps(start, |node| node.ends_with('Z')))
+            .try_fold(1, |acc, steps| Ok(lcm(acc, steps?)))
+    }
+}
@@ -0,0 +1,102 @@
+//! Day 9: Mirage Maintenance
+//! https://adventofcode.com/2023/day/9
+
+#![allow(dead_code)]
+use adventofrust::{error::Error, solution::Solution};
+
+pub fn parse_line(s: &str) -> Result<Vec<isize>, Error> {
+    s.split_whitespace()
+        .map(|x| {
+            x.parse()
+                .map_err(|e| Error::at(x, format!("Invalid value: {e}")))
+        })
+        .collect()
+}
+
+pub fn extrapolate_backward(mut nums: Vec<isize>) -> isize {
+    let Some(&first) = nums.first() else {
+        return 0;
+    };
+
+    let mut first_nums = Vec::new();
+    first_nums.push(first);
+
+    while nums.len() > 1 {
+        let mut is_same = true;
+        let ref_num = nums[1] - nums[0];
+        nums[0] = ref_num;
+        for i in 1..nums.len() - 1 {
+            let new_num = nums[i + 1] - nums[i];
+            nums[i] = new_num;
+            if new_num != ref_num {
+                is_same = false;
+            }
+        }
+        nums.pop();
+        if is_same {
+            break;
+        }
+        first_nums.push(*nums.first().expect("`nums` should not be empty."));
+    }
+    first_nums.push(*nums.first().expect("`nums` should not be empty."));
+
+    first_nums.into_iter().rev().fold(0, |acc, x| x - acc)
+}
+
+/// Extrapolating forward is the same as extrapolating the reversed history backward.
+pub fn extrapolate_forward(mut nums: Vec<isize>) -> isize {
+    nums.reverse();
+    extrapolate_backward(nums)
+}
+
+pub struct Report(Vec<Vec<isize>>);
+
+impl Solution for Report {
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(parse_line)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn part1(&self) -> Result<isize, Error> {
+        Ok(self.0.iter().cloned().map(extrapolate_forward).sum())
+    }
+
+    fn part2(&self) -> Result<isize, Error> {
+        Ok(self.0.iter().cloned().map(extrapolate_backward).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_line_0() {
+        assert_eq!(
+            18,
+            extrapolate_forward(parse_line("0 3 6 9 12 15").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_process_line_1() {
+        assert_eq!(
+            28,
+            extrapolate_forward(parse_line("1 3 6 10 15 21").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_process_line_2() {
+        assert_eq!(
+            5,
+            extrapolate_backward(parse_line("10 13 16 21 30 45").unwrap())
+        );
+    }
+}
@@ -0,0 +1,196 @@
+//! Measures of closed paths on the integer lattice, for puzzles about loops: the area they
+//! enclose, and which points or cells are inside them.
+//!
+//! A path is given by its vertices in order, either clockwise or counterclockwise, and goes
+//! back from the last vertex to the first.
+
+use crate::grid::{Grid, GridIndex, Point};
+
+/// Twice the area enclosed by `path`, with the shoelace formula. Doubling keeps it an integer.
+pub fn double_area(path: &[Point]) -> u64 {
+    edges(path)
+        .map(|(a, b)| a.column * b.row - b.column * a.row)
+        .sum::<i64>()
+        .unsigned_abs()
+}
+
+/// Number of lattice points on `path`, which is its length when it only goes along rows and
+/// columns.
+pub fn boundary_points(path: &[Point]) -> u64 {
+    edges(path)
+        .map(|(a, b)| gcd(a.row.abs_diff(b.row), a.column.abs_diff(b.column)))
+        .sum()
+}
+
+/// Number of lattice points strictly inside `path`, with Pick's theorem. When the path goes
+/// through the centers of grid cells, this is the number of cells it encloses. Paths enclosing
+/// no area have no interior.
+///
+/// Pick's theorem only holds for simple polygons: for a path that crosses or runs over itself,
+/// the count is meaningless, and `None` when it would be negative.
+pub fn interior_points(path: &[Point]) -> Option<u64> {
+    match double_area(path) {
+        0 => Some(0),
+        area => Some((area + 2).checked_sub(boundary_points(path))? / 2),
+    }
+}
+
+fn edges(path: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    path.iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Where a cell is relative to a loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Location {
+    #[default]
+    Outside,
+    /// On the loop itself.
+    Boundary,
+    Inside,
+}
+
+/// Locates every cell of `grid` relative to `path`, a loop through the cells of the grid in
+/// which each cell is a neighbor of the previous one along a row or column.
+///
+/// Each row is scanned from left to right, switching between outside and inside on every
+/// boundary cell connected to the row above.
+///
+/// # Panics
+///
+/// Panics if a cell of `path` isn't in the grid.
+pub fn locate<T>(grid: &Grid<T>, path: &[GridIndex]) -> Grid<Location> {
+    let mut locations = Grid::with_dimensions(grid.width(), grid.height());
+    let mut goes_up = Grid::<bool>::with_dimensions(grid.width(), grid.height());
+
+    for (i, &cell) in path.iter().enumerate() {
+        locations[cell] = Location::Boundary;
+        let previous = path[(i + path.len() - 1) % path.len()];
+        let next = path[(i + 1) % path.len()];
+        goes_up[cell] = [previous, next]
+            .iter()
+            .any(|other| other.row + 1 == cell.row && other.column == cell.column);
+    }
+
+    for row in 0..grid.height() {
+        let mut inside = false;
+        for column in 0..grid.width() {
+            let index = GridIndex::new(row, column);
+            if locations[index] == Location::Boundary {
+                inside ^= goes_up[index];
+            } else if inside {
+                locations[index] = Location::Inside;
+            }
+        }
+    }
+
+    locations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(vertices: &[(i64, i64)]) -> Vec<Point> {
+        vertices.iter().copied().map(Point::from).collect()
+    }
+
+    /// Every cell of the loop through the cells at `corners`, joined by straight lines.
+    fn cells(corners: &[(usize, usize)]) -> Vec<GridIndex> {
+        let mut cells = Vec::new();
+        for (i, &(row, column)) in corners.iter().enumerate() {
+            let (next_row, next_column) = corners[(i + 1) % corners.len()];
+            let steps = row.abs_diff(next_row).max(column.abs_diff(next_column));
+            let step = |from: usize, to: usize, k: usize| match from.cmp(&to) {
+                std::cmp::Ordering::Less => from + k,
+                std::cmp::Ordering::Equal => from,
+                std::cmp::Ordering::Greater => from - k,
+            };
+            cells.extend(
+                (0..steps)
+                    .map(|k| GridIndex::new(step(row, next_row, k), step(column, next_column, k))),
+            );
+        }
+        cells
+    }
+
+    #[test]
+    fn test_square() {
+        let square = points(&[(0, 0), (0, 4), (4, 4), (4, 0)]);
+
+        assert_eq!(32, double_area(&square));
+        assert_eq!(16, boundary_points(&square));
+        assert_eq!(Some(9), interior_points(&square));
+
+        let reversed = square.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(32, double_area(&reversed));
+    }
+
+    #[test]
+    fn test_triangle() {
+        let triangle = points(&[(0, 0), (0, 4), (4, 0)]);
+
+        assert_eq!(16, double_area(&triangle));
+        assert_eq!(12, boundary_points(&triangle));
+        assert_eq!(Some(3), interior_points(&triangle));
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(Some(0), interior_points(&[]));
+        assert_eq!(Some(0), interior_points(&points(&[(2, 3)])));
+        assert_eq!(Some(0), interior_points(&points(&[(0, 0), (0, 5)])));
+
+        // A thin rectangle gone around twice has twice the area but also twice the boundary.
+        let twice = points(&[(0, 0), (0, 4), (1, 4), (1, 0)]).repeat(2);
+        assert_eq!(16, double_area(&twice));
+        assert_eq!(20, boundary_points(&twice));
+        assert_eq!(None, interior_points(&twice));
+    }
+
+    #[test]
+    fn test_locate() {
+        // Loop of the second example of 2023 day 10, squeezing between pipes at the bottom.
+        let corners = [
+            (1, 1),
+            (1, 9),
+            (7, 9),
+            (7, 6),
+            (5, 6),
+            (5, 8),
+            (2, 8),
+            (2, 2),
+            (5, 2),
+            (5, 4),
+            (7, 4),
+            (7, 1),
+        ];
+        let path = cells(&corners);
+        let grid = Grid::<u8>::with_dimensions(11, 9);
+        let locations = locate(&grid, &path);
+
+        let inside = locations
+            .iter()
+            .filter(|(_, &location)| location == Location::Inside)
+            .map(|(index, _)| (index.row, index.column))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(6, 2), (6, 3), (6, 7), (6, 8)], inside);
+        assert_eq!(Location::Boundary, locations[(5, 8)]);
+        assert_eq!(Location::Outside, locations[(3, 5)]);
+
+        let vertices = corners
+            .iter()
+            .map(|&corner| Point::from(GridIndex::from(corner)))
+            .collect::<Vec<_>>();
+        assert_eq!(path.len() as u64, boundary_points(&vertices));
+        assert_eq!(Some(4), interior_points(&vertices));
+    }
+}
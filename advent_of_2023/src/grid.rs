@@ -1,6 +1,23 @@
-#![allow(dead_code)]
+//! Dense two-dimensional grid, stored row by row in a single `Vec`.
 
-use std::ops::{Index, IndexMut};
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use adventofrust::error::Error;
+
+mod bits;
+mod region;
+mod search;
+mod sparse;
+mod view;
+
+pub use bits::BitGrid;
+pub use region::{Components, Region};
+pub use sparse::{Bounds, Point, SparseGrid};
+pub use view::{GridView, GridViewMut, Ray};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Grid<T> {
@@ -9,10 +26,47 @@ pub struct Grid<T> {
     inner: Vec<T>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct GridIndex {
-    row: usize,
-    column: usize,
+    pub row: usize,
+    pub column: usize,
+}
+
+impl GridIndex {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+}
+
+/// Which cells around a cell are its neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The 4 cells sharing a side with the cell.
+    Orthogonal,
+    /// The 4 cells sharing only a corner with the cell.
+    Diagonal,
+    /// All 8 cells around the cell.
+    All,
+}
+
+impl Neighborhood {
+    /// Offsets of the neighbors as `(row, column)` deltas, in reading order.
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Self::Orthogonal => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Self::Diagonal => &[(-1, -1), (-1, 1), (1, -1), (1, 1)],
+            Self::All => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
 }
 
 impl<T> Default for Grid<T> {
@@ -30,11 +84,17 @@ impl<T> Grid<T> {
         Self::default()
     }
 
-    pub fn with_dimensions(width: usize, height: usize) -> Self {
+    /// A `width` by `height` grid filled with default values.
+    pub fn with_dimensions(width: usize, height: usize) -> Self
+    where
+        T: Default,
+    {
         Self {
             width,
             height,
-            inner: Vec::with_capacity(width * height),
+            inner: std::iter::repeat_with(T::default)
+                .take(width * height)
+                .collect(),
         }
     }
 
@@ -46,40 +106,405 @@ impl<T> Grid<T> {
         self.height
     }
 
-    pub fn append_row(&mut self) {
-        unimplemented!()
+    /// Appends a row of default values.
+    pub fn append_row(&mut self)
+    where
+        T: Default,
+    {
+        self.insert_row(self.height);
+    }
+
+    /// Appends a column of default values.
+    pub fn append_column(&mut self)
+    where
+        T: Default,
+    {
+        self.insert_column(self.width);
+    }
+
+    /// Appends a copy of `row`. See [`Grid::insert_row_with_data`].
+    pub fn append_row_with_data(&mut self, row: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_row_with_data(self.height, row);
+    }
+
+    /// Appends a copy of `column`. See [`Grid::insert_column_with_data`].
+    pub fn append_column_with_data(&mut self, column: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_column_with_data(self.width, column);
+    }
+
+    /// Inserts a row of default values before the row at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > height`.
+    pub fn insert_row(&mut self, index: usize)
+    where
+        T: Default,
+    {
+        let width = self.width;
+        self.insert_row_from(index, std::iter::repeat_with(T::default).take(width));
+    }
+
+    /// Inserts a column of default values before the column at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > width`.
+    pub fn insert_column(&mut self, index: usize)
+    where
+        T: Default,
+    {
+        let height = self.height;
+        self.insert_column_from(index, std::iter::repeat_with(T::default).take(height));
+    }
+
+    /// Inserts a copy of `row` before the row at `index`. A grid without rows takes the width of
+    /// `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > height`, or if the grid has rows of another width than `row`.
+    pub fn insert_row_with_data(&mut self, index: usize, row: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_row_from(index, row.iter().cloned());
+    }
+
+    /// Inserts a copy of `column` before the column at `index`. A grid without columns takes the
+    /// height of `column`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > width`, or if the grid has columns of another height than `column`.
+    pub fn insert_column_with_data(&mut self, index: usize, column: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_column_from(index, column.iter().cloned());
+    }
+
+    fn insert_row_from(&mut self, index: usize, row: impl ExactSizeIterator<Item = T>) {
+        assert!(
+            index <= self.height,
+            "row index {index} out of bounds for a height of {}",
+            self.height
+        );
+        if self.height == 0 {
+            self.width = row.len();
+        }
+        assert_eq!(
+            self.width,
+            row.len(),
+            "row length differs from the grid width"
+        );
+
+        let start = index * self.width;
+        self.inner.splice(start..start, row);
+        self.height += 1;
+    }
+
+    fn insert_column_from(&mut self, index: usize, column: impl ExactSizeIterator<Item = T>) {
+        assert!(
+            index <= self.width,
+            "column index {index} out of bounds for a width of {}",
+            self.width
+        );
+        if self.width == 0 {
+            self.height = column.len();
+        }
+        assert_eq!(
+            self.height,
+            column.len(),
+            "column length differs from the grid height"
+        );
+
+        let mut old = std::mem::take(&mut self.inner).into_iter();
+        self.inner.reserve((self.width + 1) * self.height);
+        for value in column {
+            self.inner.extend(old.by_ref().take(index));
+            self.inner.push(value);
+            self.inner.extend(old.by_ref().take(self.width - index));
+        }
+        self.width += 1;
+    }
+
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView::new(&self.inner, self.width, self.width, self.height)
+    }
+
+    pub fn as_view_mut(&mut self) -> GridViewMut<'_, T> {
+        GridViewMut::new(&mut self.inner, self.width, self.width, self.height)
+    }
+
+    /// The `width` by `height` region whose top left cell is `top_left`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region doesn't fit in the grid.
+    pub fn view(
+        &self,
+        top_left: impl Into<GridIndex>,
+        width: usize,
+        height: usize,
+    ) -> GridView<'_, T> {
+        self.as_view().view(top_left, width, height)
+    }
+
+    /// The `width` by `height` region whose top left cell is `top_left`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region doesn't fit in the grid.
+    pub fn view_mut(
+        &mut self,
+        top_left: impl Into<GridIndex>,
+        width: usize,
+        height: usize,
+    ) -> GridViewMut<'_, T> {
+        self.as_view_mut().into_region(top_left, width, height)
+    }
+
+    pub fn contains(&self, index: impl Into<GridIndex>) -> bool {
+        self.as_view().contains(index)
     }
 
-    pub fn append_column(&mut self) {
-        unimplemented!()
+    pub fn get(&self, index: impl Into<GridIndex>) -> Option<&T> {
+        self.as_view().get(index)
     }
 
-    pub fn append_row_with_data(&mut self, row: &[T]) {
-        unimplemented!()
+    pub fn get_mut(&mut self, index: impl Into<GridIndex>) -> Option<&mut T> {
+        let i = self.row_major_index(index.into())?;
+        Some(&mut self.inner[i])
     }
 
-    pub fn append_column_with_data(&mut self, column: &[T]) {
-        unimplemented!()
+    /// The index `(rows, columns)` away from `index`, if it is in the grid.
+    pub fn offset(&self, index: impl Into<GridIndex>, delta: (isize, isize)) -> Option<GridIndex> {
+        self.as_view().offset(index, delta)
     }
 
-    pub fn insert_row(&mut self, index: usize) {
-        unimplemented!()
+    /// The index `(rows, columns)` away from `index`, wrapping around the edges as if the grid
+    /// were a torus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't in the grid.
+    pub fn wrapping_offset(&self, index: impl Into<GridIndex>, delta: (isize, isize)) -> GridIndex {
+        self.as_view().wrapping_offset(index, delta)
     }
 
-    pub fn insert_column(&mut self, index: usize) {
-        unimplemented!()
+    /// The cell `(rows, columns)` away from `index`, if it is in the grid.
+    pub fn get_offset(&self, index: impl Into<GridIndex>, delta: (isize, isize)) -> Option<&T> {
+        self.as_view().get_offset(index, delta)
     }
 
-    pub fn insert_row_with_data(&mut self, index: usize, row: &[T]) {
-        unimplemented!()
+    /// Neighbors of `index` that are in the grid, with their indices.
+    pub fn neighbors(
+        &self,
+        index: impl Into<GridIndex>,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (GridIndex, &T)> + '_ {
+        self.as_view().neighbors(index, neighborhood)
     }
 
-    pub fn insert_column_with_data(&mut self, index: usize, column: &[T]) {
-        unimplemented!()
+    /// Neighbors of `index`, with their indices, wrapping around the edges as if the grid were
+    /// a torus. On grids narrower or shorter than 3 cells, a cell can be its own neighbor or
+    /// have the same neighbor twice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't in the grid.
+    pub fn wrapping_neighbors(
+        &self,
+        index: impl Into<GridIndex>,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (GridIndex, &T)> + '_ {
+        self.as_view().wrapping_neighbors(index, neighborhood)
+    }
+
+    fn row_major_index(&self, index: GridIndex) -> Option<usize> {
+        self.contains(index)
+            .then(|| index.row * self.width + index.column)
+    }
+
+    fn expect_index(&self, index: GridIndex) -> usize {
+        self.row_major_index(index).unwrap_or_else(|| {
+            panic!(
+                "{index:?} out of bounds for a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses one row per line of `s`, mapping each character to a cell with `cell`. Errors
+    /// point at the first character `cell` rejects, or at the first line whose length differs
+    /// from the first one.
+    pub fn parse_with(s: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, Error> {
+        let mut grid = Self::new();
+
+        for line in s.lines() {
+            let row_len = line.chars().count();
+            if grid.height > 0 && row_len != grid.width {
+                return Err(Error::at(
+                    line,
+                    format!("Expected {} cells, found {row_len}", grid.width),
+                ));
+            }
+
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    Error::at(&line[i..i + c.len_utf8()], format!("Invalid cell `{c}`"))
+                })?;
+                grid.inner.push(value);
+            }
+            grid.width = row_len;
+            grid.height += 1;
+        }
+
+        Ok(grid)
     }
 
-    fn row_major_index(&self, index: GridIndex) -> usize {
-        index.row + index.column * self.width
+    /// Renders the grid with one line per row, mapping each cell to a character with `cell`.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for (i, row) in self.inner.chunks(self.width.max(1)).enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.extend(row.iter().map(&mut cell));
+        }
+        out
+    }
+}
+
+impl<T> Grid<T> {
+    /// Indices of every cell, row by row.
+    pub fn indices(&self) -> impl Iterator<Item = GridIndex> {
+        self.as_view().indices()
+    }
+
+    /// Every cell with its index, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (GridIndex, &T)> + '_ {
+        self.indices().zip(&self.inner)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `row >= height`.
+    pub fn row(&self, row: usize) -> &[T] {
+        self.as_view().row(row)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `row >= height`.
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.height, "row {row} out of bounds");
+        &mut self.inner[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Cells of `column`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column >= width`.
+    pub fn column(
+        &self,
+        column: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        self.as_view().column(column)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        self.as_view().rows()
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_>
+           + ExactSizeIterator
+           + '_ {
+        self.as_view().columns()
+    }
+
+    /// Cells seen from `from` looking in the direction of `step`, a `(row, column)` delta, up to
+    /// the edge of the grid, with their indices. `from` itself isn't included; `.rev()` goes
+    /// from the edge back towards `from`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `(0, 0)`.
+    pub fn ray(&self, from: impl Into<GridIndex>, step: (isize, isize)) -> Ray<'_, T> {
+        self.as_view().ray(from, step)
+    }
+
+    /// Diagonals going down and to the right, from the one starting at the bottom left corner to
+    /// the one starting at the top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> + '_ {
+        self.as_view().diagonals()
+    }
+
+    /// Diagonals going down and to the left, from the one starting at the top left corner to
+    /// the one starting at the bottom right corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> + '_ {
+        self.as_view().anti_diagonals()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        self.as_view().transpose()
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        self.as_view().rotate_clockwise()
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        self.as_view().rotate_counterclockwise()
+    }
+
+    pub fn rotate_half(&self) -> Self {
+        self.as_view().rotate_half()
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        self.as_view().flip_horizontal()
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        self.as_view().flip_vertical()
+    }
+}
+
+/// Parses cells with their `TryFrom<char>` implementation. See [`Grid::parse_with`].
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| T::try_from(c).ok())
+    }
+}
+
+/// Renders cells with their conversion to `char`, so that parsing the output gives the grid
+/// back.
+impl<T: Copy> Display for Grid<T>
+where
+    char: From<T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(|&value| char::from(value)))
     }
 }
 
@@ -87,13 +512,14 @@ impl<T, I: Into<GridIndex>> Index<I> for Grid<T> {
     type Output = T;
 
     fn index(&self, index: I) -> &Self::Output {
-        self.inner.index(self.row_major_index(index.into()))
+        self.inner.index(self.expect_index(index.into()))
     }
 }
 
 impl<T, I: Into<GridIndex>> IndexMut<I> for Grid<T> {
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
-        self.inner.index_mut(self.row_major_index(index.into()))
+        let i = self.expect_index(index.into());
+        self.inner.index_mut(i)
     }
 }
 
@@ -102,3 +528,316 @@ impl From<(usize, usize)> for GridIndex {
         Self { row, column }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &[&[u8]]) -> Grid<u8> {
+        let mut grid = Grid::new();
+        for row in rows {
+            grid.append_row_with_data(row);
+        }
+        grid
+    }
+
+    #[test]
+    fn test_with_dimensions() {
+        let grid = Grid::<u8>::with_dimensions(3, 2);
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(vec![0; 6], grid.inner);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut grid = grid(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], grid.inner);
+
+        grid.append_column_with_data(&[7, 8]);
+        assert_eq!(vec![1, 2, 3, 7, 4, 5, 6, 8], grid.inner);
+
+        grid.append_row();
+        grid.append_column();
+        assert_eq!((5, 3), (grid.width(), grid.height()));
+        assert_eq!(
+            vec![1, 2, 3, 7, 0, 4, 5, 6, 8, 0, 0, 0, 0, 0, 0],
+            grid.inner
+        );
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut grid = grid(&[&[1, 2], &[3, 4]]);
+
+        grid.insert_row_with_data(1, &[5, 6]);
+        assert_eq!(vec![1, 2, 5, 6, 3, 4], grid.inner);
+
+        grid.insert_column_with_data(0, &[7, 8, 9]);
+        assert_eq!(vec![7, 1, 2, 8, 5, 6, 9, 3, 4], grid.inner);
+
+        grid.insert_column(2);
+        grid.insert_row(0);
+        assert_eq!((4, 4), (grid.width(), grid.height()));
+        assert_eq!(
+            vec![0, 0, 0, 0, 7, 1, 0, 2, 8, 5, 0, 6, 9, 3, 0, 4],
+            grid.inner
+        );
+    }
+
+    #[test]
+    fn test_first_column_sets_height() {
+        let mut grid = Grid::new();
+        grid.append_column_with_data(&[1, 2, 3]);
+        grid.append_column_with_data(&[4, 5, 6]);
+        assert_eq!((2, 3), (grid.width(), grid.height()));
+        assert_eq!(vec![1, 4, 2, 5, 3, 6], grid.inner);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = ();
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '.' => Ok(Self::Open),
+                '#' => Ok(Self::Wall),
+                _ => Err(()),
+            }
+        }
+    }
+
+    impl From<Tile> for char {
+        fn from(value: Tile) -> Self {
+            match value {
+                Tile::Open => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<Tile> = "..#\n#..\n".parse().unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(
+            vec![
+                Tile::Open,
+                Tile::Open,
+                Tile::Wall,
+                Tile::Wall,
+                Tile::Open,
+                Tile::Open
+            ],
+            grid.inner
+        );
+
+        let digits = Grid::parse_with("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(vec![1, 2, 3, 4], digits.inner);
+
+        assert_eq!(Grid::<Tile>::new(), "".parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let position = |input: &str| {
+            let error = input.parse::<Grid<Tile>>().unwrap_err().locate(input);
+            let location = error.location().unwrap();
+            (location.line, location.column, location.len)
+        };
+
+        assert_eq!((2, 2, 1), position("..#\n#x.\n"));
+        assert_eq!((2, 1, 2), position("..#\n#.\n"));
+        assert_eq!((3, 1, 4), position("..#\n#..\n....\n"));
+    }
+
+    #[test]
+    fn test_render_round_trip() {
+        let input = "..#.\n#...\n.##.";
+        let grid: Grid<Tile> = input.parse().unwrap();
+        assert_eq!(input, grid.to_string());
+
+        let bytes: Grid<u8> = "abc\ndef".parse().unwrap();
+        assert_eq!("abc\ndef", bytes.to_string());
+        assert_eq!("ABC\nDEF", bytes.render(|b| b.to_ascii_uppercase() as char));
+        assert_eq!(bytes, bytes.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn test_index() {
+        let mut grid = grid(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(6, grid[(1, 2)]);
+        assert_eq!(3, grid[GridIndex::new(0, 2)]);
+
+        grid[(1, 0)] = 7;
+        assert_eq!(Some(&7), grid.get((1, 0)));
+        assert_eq!(None, grid.get((0, 3)));
+        assert_eq!(None, grid.get((2, 0)));
+
+        *grid.get_mut((0, 0)).unwrap() = 8;
+        assert_eq!(8, grid[(0, 0)]);
+        assert_eq!(None, grid.get_mut((0, 3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for a 3x2 grid")]
+    fn test_index_out_of_bounds() {
+        // (0, 4) would be cell 4 of the row-major storage, which exists.
+        let _ = grid(&[&[1, 2, 3], &[4, 5, 6]])[(0, 4)];
+    }
+
+    #[test]
+    fn test_offset() {
+        let grid = grid(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(Some(GridIndex::new(0, 1)), grid.offset((1, 2), (-1, -1)));
+        assert_eq!(None, grid.offset((0, 0), (-1, 0)));
+        assert_eq!(None, grid.offset((0, 2), (0, 1)));
+        assert_eq!(Some(&4), grid.get_offset((0, 1), (1, -1)));
+
+        assert_eq!(GridIndex::new(1, 0), grid.wrapping_offset((0, 0), (-1, 0)));
+        assert_eq!(GridIndex::new(0, 0), grid.wrapping_offset((0, 2), (0, 1)));
+        assert_eq!(GridIndex::new(1, 1), grid.wrapping_offset((0, 0), (-3, -5)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]);
+        let values = |neighbors: Vec<(GridIndex, &u8)>| {
+            neighbors.into_iter().map(|(_, &v)| v).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![2, 4, 6, 8],
+            values(grid.neighbors((1, 1), Neighborhood::Orthogonal).collect())
+        );
+        assert_eq!(
+            vec![1, 3, 7, 9],
+            values(grid.neighbors((1, 1), Neighborhood::Diagonal).collect())
+        );
+        assert_eq!(
+            vec![2, 4, 5],
+            values(grid.neighbors((0, 0), Neighborhood::All).collect())
+        );
+        assert_eq!(
+            vec![(GridIndex::new(1, 1), &5)],
+            grid.neighbors((2, 2), Neighborhood::Diagonal)
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![7, 3, 2, 4],
+            values(
+                grid.wrapping_neighbors((0, 0), Neighborhood::Orthogonal)
+                    .collect()
+            )
+        );
+        assert_eq!(
+            vec![9, 7, 8, 3, 2, 6, 4, 5],
+            values(grid.wrapping_neighbors((0, 0), Neighborhood::All).collect())
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = grid(&[&[1, 2, 3], &[4, 5, 6]]);
+
+        assert_eq!(
+            vec![&[1, 2, 3][..], &[4, 5, 6]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]],
+            grid.columns()
+                .map(|column| column.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![6, 3],
+            grid.column(2).rev().copied().collect::<Vec<_>>()
+        );
+
+        grid.row_mut(1)[0] = 7;
+        assert_eq!(&[7, 5, 6], grid.row(1));
+
+        assert_eq!(
+            vec![(GridIndex::new(1, 1), &5), (GridIndex::new(1, 2), &6)],
+            grid.iter().skip(4).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let grid = grid(&[&[1, 2, 3], &[4, 5, 6]]);
+        let values = |diagonal: Ray<u8>| diagonal.map(|(_, &v)| v).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![vec![4], vec![1, 5], vec![2, 6], vec![3]],
+            grid.diagonals().map(values).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![vec![1], vec![2, 4], vec![3, 5], vec![6]],
+            grid.anti_diagonals().map(values).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        fn values<'a>(ray: impl Iterator<Item = (GridIndex, &'a u8)>) -> Vec<u8> {
+            ray.map(|(_, &v)| v).collect()
+        }
+
+        let grid = grid(&[&[1, 2, 3, 4], &[5, 6, 7, 8], &[9, 10, 11, 12]]);
+
+        assert_eq!(vec![7, 8], values(grid.ray((1, 1), (0, 1))));
+        assert_eq!(vec![5], values(grid.ray((1, 1), (0, -1))));
+        assert_eq!(vec![2], values(grid.ray((1, 1), (-1, 0))));
+        assert_eq!(vec![11], values(grid.ray((0, 0), (1, 1)).skip(1)));
+        assert_eq!(vec![11, 6], values(grid.ray((0, 0), (1, 1)).rev()));
+        assert_eq!(vec![7], values(grid.ray((0, 0), (1, 2))));
+        assert_eq!(0, grid.ray((0, 3), (0, 1)).len());
+        assert_eq!(0, grid.ray((5, 5), (0, 1)).len());
+        assert_eq!(
+            Some((GridIndex::new(0, 3), &4)),
+            grid.ray((2, 1), (-1, 1)).next_back()
+        );
+    }
+
+    #[test]
+    fn test_ray_viewing_distance() {
+        // Scenic scores of Advent of Code 2022 day 8.
+        let trees =
+            Grid::parse_with("30373\n25512\n65332\n33549\n35390", |c| c.to_digit(10)).unwrap();
+        let score = |index: GridIndex| {
+            let height = trees[index];
+            Neighborhood::Orthogonal
+                .offsets()
+                .iter()
+                .map(|&step| {
+                    let mut ray = trees.ray(index, step);
+                    let len = ray.len();
+                    ray.position(|(_, &tree)| tree >= height)
+                        .map_or(len, |i| i + 1)
+                })
+                .product::<usize>()
+        };
+
+        assert_eq!(8, trees.indices().map(score).max().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "row length differs")]
+    fn test_append_ragged_row() {
+        grid(&[&[1, 2, 3], &[4, 5]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_insert_column_out_of_bounds() {
+        grid(&[&[1, 2]]).insert_column_with_data(3, &[0]);
+    }
+}
@@ -0,0 +1,362 @@
+//! Grid of booleans packed 64 to a word, so that whole-grid operations, such as the step of a
+//! cellular automaton, work on a word at a time.
+
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Not};
+
+use super::{Grid, GridIndex};
+
+const BITS: usize = u64::BITS as usize;
+
+/// Booleans on a grid, stored row by row with each row starting on a new word. Bits past the
+/// width in the last word of a row are always unset.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    /// Words per row.
+    stride: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// A `width` by `height` grid with every cell unset.
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(BITS);
+        Self {
+            width,
+            height,
+            stride,
+            words: vec![0; stride * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, index: impl Into<GridIndex>) -> bool {
+        let index = index.into();
+        index.row < self.height && index.column < self.width
+    }
+
+    /// Word and mask of the bit of `index`, if it is in the grid.
+    fn bit(&self, index: GridIndex) -> Option<(usize, u64)> {
+        self.contains(index).then(|| {
+            (
+                index.row * self.stride + index.column / BITS,
+                1 << (index.column % BITS),
+            )
+        })
+    }
+
+    pub fn get(&self, index: impl Into<GridIndex>) -> Option<bool> {
+        let (word, mask) = self.bit(index.into())?;
+        Some(self.words[word] & mask != 0)
+    }
+
+    /// Sets the cell at `index` to `value`, returning its previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't in the grid.
+    pub fn set(&mut self, index: impl Into<GridIndex>, value: bool) -> bool {
+        let index = index.into();
+        let (word, mask) = self.bit(index).unwrap_or_else(|| {
+            panic!(
+                "{index:?} out of bounds for a {}x{} grid",
+                self.width, self.height
+            )
+        });
+
+        let previous = self.words[word] & mask != 0;
+        if value {
+            self.words[word] |= mask;
+        } else {
+            self.words[word] &= !mask;
+        }
+        previous
+    }
+
+    /// Sets every cell to `value`.
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(if value { !0 } else { 0 });
+        self.clear_padding();
+    }
+
+    /// Number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&word| word != 0)
+    }
+
+    /// Every cell with its index, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (GridIndex, bool)> + '_ {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |row| (0..width).map(move |column| GridIndex { row, column }))
+            .map(|index| (index, self[index]))
+    }
+
+    /// Indices of the set cells, row by row.
+    pub fn ones(&self) -> impl Iterator<Item = GridIndex> + '_ {
+        self.words
+            .chunks(self.stride.max(1))
+            .enumerate()
+            .flat_map(|(row, words)| {
+                words.iter().enumerate().flat_map(move |(i, &word)| {
+                    let mut word = word;
+                    std::iter::from_fn(move || {
+                        (word != 0).then(|| {
+                            let bit = word.trailing_zeros() as usize;
+                            word &= word - 1;
+                            GridIndex::new(row, i * BITS + bit)
+                        })
+                    })
+                })
+            })
+    }
+
+    /// The grid moved one row up, dropping the top row and leaving the bottom one unset.
+    pub fn shift_up(&self) -> Self {
+        let mut words = vec![0; self.words.len()];
+        let start = self.stride.min(words.len());
+        words[..self.words.len() - start].copy_from_slice(&self.words[start..]);
+        Self { words, ..*self }
+    }
+
+    /// The grid moved one row down, dropping the bottom row and leaving the top one unset.
+    pub fn shift_down(&self) -> Self {
+        let mut words = vec![0; self.words.len()];
+        let start = self.stride.min(words.len());
+        words[start..].copy_from_slice(&self.words[..self.words.len() - start]);
+        Self { words, ..*self }
+    }
+
+    /// The grid moved one column left, dropping the left column and leaving the right one
+    /// unset.
+    pub fn shift_left(&self) -> Self {
+        let mut shifted = self.clone();
+        for row in shifted.words.chunks_mut(self.stride.max(1)) {
+            for i in 0..row.len() {
+                let carry = row.get(i + 1).map_or(0, |next| next << (BITS - 1));
+                row[i] = row[i] >> 1 | carry;
+            }
+        }
+        shifted
+    }
+
+    /// The grid moved one column right, dropping the right column and leaving the left one
+    /// unset.
+    pub fn shift_right(&self) -> Self {
+        let mut shifted = self.clone();
+        for row in shifted.words.chunks_mut(self.stride.max(1)) {
+            for i in (0..row.len()).rev() {
+                let carry = i
+                    .checked_sub(1)
+                    .map_or(0, |previous| row[previous] >> (BITS - 1));
+                row[i] = row[i] << 1 | carry;
+            }
+        }
+        shifted.clear_padding();
+        shifted
+    }
+
+    /// Unsets the bits past the width in the last word of each row.
+    fn clear_padding(&mut self) {
+        let used = self.width % BITS;
+        if used == 0 {
+            return;
+        }
+        for row in self.words.chunks_mut(self.stride) {
+            if let Some(last) = row.last_mut() {
+                *last &= (1 << used) - 1;
+            }
+        }
+    }
+
+    fn assert_same_dimensions(&self, other: &Self) {
+        assert!(
+            (self.width, self.height) == (other.width, other.height),
+            "{}x{} and {}x{} grids differ in dimensions",
+            self.width,
+            self.height,
+            other.width,
+            other.height
+        );
+    }
+
+    fn zip_with(&mut self, other: &Self, op: impl Fn(u64, u64) -> u64) {
+        self.assert_same_dimensions(other);
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            *word = op(*word, other);
+        }
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+        for (index, &value) in grid.iter() {
+            if value {
+                bits.set(index, true);
+            }
+        }
+        bits
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(bits: &BitGrid) -> Self {
+        Grid {
+            width: bits.width,
+            height: bits.height,
+            inner: bits.iter().map(|(_, value)| value).collect(),
+        }
+    }
+}
+
+impl<I: Into<GridIndex>> Index<I> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, index: I) -> &Self::Output {
+        let index = index.into();
+        match self.get(index) {
+            Some(true) => &true,
+            Some(false) => &false,
+            None => panic!(
+                "{index:?} out of bounds for a {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> Self::Output {
+        let mut inverted = self.clone();
+        for word in &mut inverted.words {
+            *word = !*word;
+        }
+        inverted.clear_padding();
+        inverted
+    }
+}
+
+/// Implements a bitwise operator between two grids of the same dimensions, which panics when
+/// they differ.
+macro_rules! bitwise {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident, $word:expr) => {
+        impl $assign<&BitGrid> for BitGrid {
+            fn $assign_method(&mut self, other: &BitGrid) {
+                self.zip_with(other, $word);
+            }
+        }
+
+        impl $op for &BitGrid {
+            type Output = BitGrid;
+
+            fn $method(self, other: &BitGrid) -> Self::Output {
+                let mut result = self.clone();
+                result.$assign_method(other);
+                result
+            }
+        }
+    };
+}
+
+bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+bitwise!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| a ^ b);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(s: &str) -> BitGrid {
+        BitGrid::from(&Grid::parse_with(s, |c| Some(c == '#')).unwrap())
+    }
+
+    fn render(bits: &BitGrid) -> String {
+        Grid::from(bits).render(|&value| if value { '#' } else { '.' })
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut grid = BitGrid::new(70, 2);
+        assert!(!grid.set((1, 65), true));
+        assert!(grid.set((1, 65), true));
+        grid.set((0, 3), true);
+
+        assert_eq!(Some(true), grid.get((1, 65)));
+        assert_eq!(Some(false), grid.get((0, 65)));
+        assert_eq!(None, grid.get((0, 70)));
+        assert!(grid[(0, 3)]);
+        assert_eq!(2, grid.count_ones());
+        assert_eq!(
+            vec![GridIndex::new(0, 3), GridIndex::new(1, 65)],
+            grid.ones().collect::<Vec<_>>()
+        );
+
+        grid.fill(true);
+        assert_eq!(140, grid.count_ones());
+    }
+
+    #[test]
+    fn test_bitwise() {
+        let a = bits("##..\n#.#.");
+        let b = bits("#.#.\n..##");
+
+        assert_eq!("#...\n..#.", render(&(&a & &b)));
+        assert_eq!("###.\n#.##", render(&(&a | &b)));
+        assert_eq!(".##.\n#..#", render(&(&a ^ &b)));
+        assert_eq!("..##\n.#.#", render(&!&a));
+        assert_eq!(8, (!&a).count_ones() + a.count_ones());
+    }
+
+    #[test]
+    fn test_shifts() {
+        let grid = bits("#..#\n.##.\n#...");
+
+        assert_eq!(".##.\n#...\n....", render(&grid.shift_up()));
+        assert_eq!("....\n#..#\n.##.", render(&grid.shift_down()));
+        assert_eq!("..#.\n##..\n....", render(&grid.shift_left()));
+        assert_eq!(".#..\n..##\n.#..", render(&grid.shift_right()));
+    }
+
+    #[test]
+    fn test_shifts_across_words() {
+        let mut grid = BitGrid::new(130, 1);
+        grid.set((0, 63), true);
+        grid.set((0, 64), true);
+        grid.set((0, 129), true);
+
+        let right = grid.shift_right();
+        assert_eq!(
+            vec![GridIndex::new(0, 64), GridIndex::new(0, 65)],
+            right.ones().collect::<Vec<_>>()
+        );
+        let left = grid.shift_left();
+        assert_eq!(
+            vec![62, 63, 128],
+            left.ones().map(|index| index.column).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "differ in dimensions")]
+    fn test_bitwise_dimensions() {
+        let _ = &BitGrid::new(2, 2) & &BitGrid::new(2, 3);
+    }
+}
@@ -0,0 +1,285 @@
+//! Connected regions of a grid: flood fill from a cell, and labeling of every region at once.
+
+use super::{Grid, GridIndex, Neighborhood};
+
+/// Cells connected to each other, in reading order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    cells: Vec<GridIndex>,
+}
+
+impl Region {
+    fn new(mut cells: Vec<GridIndex>) -> Self {
+        cells.sort_unstable();
+        Self { cells }
+    }
+
+    pub fn cells(&self) -> &[GridIndex] {
+        &self.cells
+    }
+
+    /// Number of cells.
+    pub fn size(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, index: impl Into<GridIndex>) -> bool {
+        self.cells.binary_search(&index.into()).is_ok()
+    }
+
+    /// Top left and bottom right corners of the smallest rectangle holding the region, or
+    /// `None` for an empty region.
+    pub fn bounds(&self) -> Option<(GridIndex, GridIndex)> {
+        let first = self.cells.first()?;
+        let last = self.cells.last()?;
+        let columns = self.cells.iter().map(|index| index.column);
+        let left = columns.clone().min()?;
+        let right = columns.max()?;
+        Some((
+            GridIndex::new(first.row, left),
+            GridIndex::new(last.row, right),
+        ))
+    }
+
+    /// Whether the cell `(rows, columns)` away from `index` is in the region.
+    fn has(&self, index: GridIndex, (rows, columns): (isize, isize)) -> bool {
+        let moved = index
+            .row
+            .checked_add_signed(rows)
+            .zip(index.column.checked_add_signed(columns));
+        moved.is_some_and(|moved| self.contains(moved))
+    }
+
+    /// Number of cell sides between the region and the outside, including the sides of holes.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&index| {
+                Neighborhood::Orthogonal
+                    .offsets()
+                    .iter()
+                    .filter(|&&delta| !self.has(index, delta))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of straight sides of the outline of the region, including the outlines of holes.
+    /// Cells touching only by a corner count as two separate outlines.
+    pub fn sides(&self) -> usize {
+        // A polygon has as many sides as corners, and each corner is at a corner of a cell.
+        self.cells
+            .iter()
+            .map(|&index| {
+                Neighborhood::Diagonal
+                    .offsets()
+                    .iter()
+                    .filter(|&&(rows, columns)| {
+                        let vertical = self.has(index, (rows, 0));
+                        let horizontal = self.has(index, (0, columns));
+                        let diagonal = self.has(index, (rows, columns));
+                        (!vertical && !horizontal) || (vertical && horizontal && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+/// Every region of a grid, as labeled by [`Grid::components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl Components {
+    /// Label of the region of each cell, which is its index in [`Components::regions`], or
+    /// `None` for cells left out of every region.
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    /// Regions in the reading order of their first cell.
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// The region holding `index`, if any.
+    pub fn region_of(&self, index: impl Into<GridIndex>) -> Option<&Region> {
+        let label = (*self.labels.get(index)?)?;
+        Some(&self.regions[label])
+    }
+
+    /// Number of cells of each region.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.regions.iter().map(Region::size)
+    }
+}
+
+impl<T> Grid<T> {
+    /// The cells reachable from `seed` through cells on which `passable` is true, `seed`
+    /// included. The region is empty when `seed` itself isn't passable or in the grid.
+    pub fn flood_fill(
+        &self,
+        seed: impl Into<GridIndex>,
+        neighborhood: Neighborhood,
+        mut passable: impl FnMut(&T) -> bool,
+    ) -> Region {
+        let seed = seed.into();
+        if !self.get(seed).is_some_and(&mut passable) {
+            return Region::new(Vec::new());
+        }
+
+        let mut seen = Grid::<bool>::with_dimensions(self.width, self.height);
+        seen[seed] = true;
+        let mut stack = vec![seed];
+        let mut cells = Vec::new();
+        while let Some(index) = stack.pop() {
+            cells.push(index);
+            for (neighbor, value) in self.neighbors(index, neighborhood) {
+                if !seen[neighbor] && passable(value) {
+                    seen[neighbor] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        Region::new(cells)
+    }
+
+    /// Labels the regions of neighboring cells of equal `key`. Cells whose key is `None` are
+    /// left out of every region, so `|&c| (c == '#').then_some(())` finds islands of `#`, while
+    /// `|&c| Some(c)` splits the whole grid into areas of the same symbol.
+    pub fn components<K: PartialEq>(
+        &self,
+        neighborhood: Neighborhood,
+        key: impl FnMut(&T) -> Option<K>,
+    ) -> Components {
+        let keys = Grid {
+            width: self.width,
+            height: self.height,
+            inner: self.inner.iter().map(key).collect(),
+        };
+        let mut labels = Grid::<Option<usize>>::with_dimensions(self.width, self.height);
+        let mut regions = Vec::new();
+
+        for seed in self.indices() {
+            if labels[seed].is_some() || keys[seed].is_none() {
+                continue;
+            }
+
+            let label = Some(regions.len());
+            labels[seed] = label;
+            let mut stack = vec![seed];
+            let mut cells = Vec::new();
+            while let Some(index) = stack.pop() {
+                cells.push(index);
+                for (neighbor, key) in keys.neighbors(index, neighborhood) {
+                    if labels[neighbor].is_none() && *key == keys[index] {
+                        labels[neighbor] = label;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            regions.push(Region::new(cells));
+        }
+
+        Components { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Example of Advent of Code 2024, day 12.
+    const GARDEN: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = "#..#\n.##.\n#..#".parse::<Grid<char>>().unwrap();
+
+        let region = grid.flood_fill((0, 1), Neighborhood::Orthogonal, |&c| c == '.');
+        assert_eq!(2, region.size());
+        assert!(region.contains((0, 2)) && !region.contains((1, 0)));
+
+        let region = grid.flood_fill((0, 1), Neighborhood::All, |&c| c == '.');
+        assert_eq!(6, region.size());
+        assert_eq!(
+            Some((GridIndex::new(0, 0), GridIndex::new(2, 3))),
+            region.bounds()
+        );
+
+        assert!(grid
+            .flood_fill((0, 0), Neighborhood::All, |&c| c == '.')
+            .is_empty());
+        assert!(grid
+            .flood_fill((5, 5), Neighborhood::All, |&c| c == '.')
+            .is_empty());
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = GARDEN.parse::<Grid<char>>().unwrap();
+        let components = grid.components(Neighborhood::Orthogonal, |&c| Some(c));
+
+        assert_eq!(vec![4, 4, 4, 1, 3], components.sizes().collect::<Vec<_>>());
+        assert_eq!(Some(2), components.labels()[(3, 3)]);
+        assert_eq!(
+            Some((GridIndex::new(1, 2), GridIndex::new(3, 3))),
+            components.region_of((2, 2)).unwrap().bounds()
+        );
+        assert_eq!(
+            vec![10, 8, 10, 4, 8],
+            components
+                .regions()
+                .iter()
+                .map(Region::perimeter)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![4, 4, 8, 4, 4],
+            components
+                .regions()
+                .iter()
+                .map(Region::sides)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_islands() {
+        let grid = "#.#\n.#.\n#.#\n...".parse::<Grid<char>>().unwrap();
+
+        let islands = grid.components(Neighborhood::Orthogonal, |&c| (c == '#').then_some(()));
+        assert_eq!(5, islands.regions().len());
+        assert_eq!(None, islands.region_of((0, 1)));
+
+        let islands = grid.components(Neighborhood::All, |&c| (c == '#').then_some(()));
+        assert_eq!(1, islands.regions().len());
+        assert_eq!(20, islands.regions()[0].perimeter());
+        assert_eq!(20, islands.regions()[0].sides());
+    }
+
+    #[test]
+    fn test_sides_with_hole() {
+        let grid = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO"
+            .parse::<Grid<char>>()
+            .unwrap();
+        let components = grid.components(Neighborhood::Orthogonal, |&c| Some(c));
+        let outer = components.region_of((0, 0)).unwrap();
+
+        assert_eq!(21, outer.size());
+        assert_eq!(36, outer.perimeter());
+        assert_eq!(20, outer.sides());
+    }
+}
@@ -0,0 +1,165 @@
+//! Shortest paths between the cells of a grid, on top of [`adventofrust::utils::search`].
+
+use std::ops::Add;
+
+use adventofrust::utils::search::{self, Paths};
+
+use super::{Grid, GridIndex, Neighborhood};
+
+/// Lower bound of the number of moves between two cells, for A* on a grid.
+fn moves(neighborhood: Neighborhood, from: GridIndex, to: GridIndex) -> usize {
+    let rows = from.row.abs_diff(to.row);
+    let columns = from.column.abs_diff(to.column);
+    match neighborhood {
+        Neighborhood::Orthogonal => rows + columns,
+        Neighborhood::Diagonal | Neighborhood::All => rows.max(columns),
+    }
+}
+
+impl<T> Grid<T> {
+    /// Neighbors of `index` on which `walkable` is true.
+    pub fn walkable_neighbors<'a>(
+        &'a self,
+        index: impl Into<GridIndex>,
+        neighborhood: Neighborhood,
+        mut walkable: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = GridIndex> + 'a {
+        self.neighbors(index, neighborhood)
+            .filter_map(move |(neighbor, value)| walkable(value).then_some(neighbor))
+    }
+
+    /// Number of moves from `start` to every cell reachable through cells on which `walkable`
+    /// is true.
+    pub fn distances_from(
+        &self,
+        start: impl Into<GridIndex>,
+        neighborhood: Neighborhood,
+        mut walkable: impl FnMut(&T) -> bool,
+    ) -> Paths<GridIndex, usize> {
+        search::bfs(
+            [start.into()],
+            |&index| {
+                self.walkable_neighbors(index, neighborhood, &mut walkable)
+                    .collect::<Vec<_>>()
+            },
+            |_| false,
+        )
+    }
+
+    /// A path with the fewest moves from `start` to `goal` through cells on which `walkable` is
+    /// true, both included.
+    pub fn shortest_path(
+        &self,
+        start: impl Into<GridIndex>,
+        goal: impl Into<GridIndex>,
+        neighborhood: Neighborhood,
+        mut walkable: impl FnMut(&T) -> bool,
+    ) -> Option<Vec<GridIndex>> {
+        let goal = goal.into();
+        search::astar(
+            [start.into()],
+            |&index| {
+                self.walkable_neighbors(index, neighborhood, &mut walkable)
+                    .map(|neighbor| (neighbor, 1))
+                    .collect::<Vec<_>>()
+            },
+            |&index| moves(neighborhood, index, goal),
+            |&index| index == goal,
+        )
+        .goal_path()
+    }
+
+    /// The cheapest path from `start` to `goal`, where moving onto a cell costs `cost` of it, or
+    /// is impossible when that is `None`. Returns the total cost with the path, both ends
+    /// included.
+    pub fn cheapest_path<C>(
+        &self,
+        start: impl Into<GridIndex>,
+        goal: impl Into<GridIndex>,
+        neighborhood: Neighborhood,
+        mut cost: impl FnMut(&T) -> Option<C>,
+    ) -> Option<(C, Vec<GridIndex>)>
+    where
+        C: Copy + Ord + Default + Add<Output = C>,
+    {
+        let goal = goal.into();
+        let paths = search::dijkstra(
+            [start.into()],
+            |&index| {
+                self.neighbors(index, neighborhood)
+                    .filter_map(|(neighbor, value)| Some((neighbor, cost(value)?)))
+                    .collect::<Vec<_>>()
+            },
+            |&index| index == goal,
+        );
+        Some((paths.goal_distance()?, paths.goal_path()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#G";
+
+    fn maze() -> Grid<char> {
+        MAZE.parse().unwrap()
+    }
+
+    #[test]
+    fn test_distances_from() {
+        let grid = maze();
+        let paths = grid.distances_from((0, 0), Neighborhood::Orthogonal, |&c| c != '#');
+
+        assert_eq!(Some(4), paths.distance(&GridIndex::new(2, 2)));
+        assert_eq!(Some(5), paths.distance(&GridIndex::new(4, 1)));
+        assert_eq!(None, paths.distance(&GridIndex::new(1, 1)));
+        assert_eq!(
+            Some(
+                vec![(0, 0), (0, 1), (0, 2), (1, 2)]
+                    .into_iter()
+                    .map(GridIndex::from)
+                    .collect()
+            ),
+            paths.path(&GridIndex::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let grid = maze();
+        let path = grid
+            .shortest_path((0, 0), (4, 7), Neighborhood::Orthogonal, |&c| c != '#')
+            .unwrap();
+
+        assert_eq!(16, path.len());
+        assert_eq!(GridIndex::new(0, 0), path[0]);
+        assert_eq!(GridIndex::new(4, 7), path[15]);
+        assert!(path.iter().all(|&index| grid[index] != '#'));
+
+        let diagonal = grid
+            .shortest_path((0, 0), (4, 7), Neighborhood::All, |&c| c != '#')
+            .unwrap();
+        assert_eq!(9, diagonal.len());
+        assert_eq!(
+            None,
+            grid.shortest_path((0, 0), (1, 1), Neighborhood::All, |&c| c != '#')
+        );
+    }
+
+    #[test]
+    fn test_cheapest_path() {
+        let grid = Grid::parse_with("1163\n1381\n2136", |c| c.to_digit(10)).unwrap();
+        let (cost, path) = grid
+            .cheapest_path((0, 0), (2, 3), Neighborhood::Orthogonal, |&risk| Some(risk))
+            .unwrap();
+
+        assert_eq!(13, cost);
+        assert_eq!(6, path.len());
+    }
+}
@@ -0,0 +1,366 @@
+//! Grid over the whole plane, storing only the cells that were set, for simulations whose
+//! extent isn't known up front.
+
+use std::collections::{hash_map, HashMap};
+
+use super::{Grid, GridIndex, Neighborhood};
+
+/// Position on the plane, which unlike [`GridIndex`] may be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub row: i64,
+    pub column: i64,
+}
+
+impl Point {
+    pub const fn new(row: i64, column: i64) -> Self {
+        Self { row, column }
+    }
+
+    /// The point `(rows, columns)` away.
+    pub fn offset(self, (rows, columns): (isize, isize)) -> Self {
+        Self {
+            row: self.row + rows as i64,
+            column: self.column + columns as i64,
+        }
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((row, column): (i64, i64)) -> Self {
+        Self { row, column }
+    }
+}
+
+impl From<GridIndex> for Point {
+    fn from(index: GridIndex) -> Self {
+        Self::new(index.row as i64, index.column as i64)
+    }
+}
+
+/// Smallest rectangle holding a set of points, with both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub top_left: Point,
+    pub bottom_right: Point,
+}
+
+impl Bounds {
+    fn point(point: Point) -> Self {
+        Self {
+            top_left: point,
+            bottom_right: point,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.bottom_right.column - self.top_left.column + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.bottom_right.row - self.top_left.row + 1) as usize
+    }
+
+    pub fn contains(&self, point: impl Into<Point>) -> bool {
+        let point = point.into();
+        (self.top_left.row..=self.bottom_right.row).contains(&point.row)
+            && (self.top_left.column..=self.bottom_right.column).contains(&point.column)
+    }
+
+    /// The bounds grown to hold `point`.
+    pub fn including(self, point: impl Into<Point>) -> Self {
+        let point = point.into();
+        Self {
+            top_left: Point::new(
+                self.top_left.row.min(point.row),
+                self.top_left.column.min(point.column),
+            ),
+            bottom_right: Point::new(
+                self.bottom_right.row.max(point.row),
+                self.bottom_right.column.max(point.column),
+            ),
+        }
+    }
+
+    /// Index in a grid of these bounds of `point`, which must be in them.
+    fn grid_index(&self, point: Point) -> GridIndex {
+        GridIndex::new(
+            (point.row - self.top_left.row) as usize,
+            (point.column - self.top_left.column) as usize,
+        )
+    }
+}
+
+/// Cells set anywhere on the plane, backed by a `HashMap`. Setting a cell never moves the
+/// others, and the bounds of the set cells are kept up to date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cells of `grid` for which `keep` is true, with the cell at index `(row, column)` at
+    /// the point `(row, column)`.
+    pub fn from_grid(grid: Grid<T>, mut keep: impl FnMut(&T) -> bool) -> Self {
+        let width = grid.width();
+        grid.inner
+            .into_iter()
+            .enumerate()
+            .filter(|(_, value)| keep(value))
+            .map(|(i, value)| (Point::new((i / width) as i64, (i % width) as i64), value))
+            .collect()
+    }
+
+    /// Number of set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Smallest rectangle holding every set cell, or `None` when there are none.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn contains(&self, point: impl Into<Point>) -> bool {
+        self.cells.contains_key(&point.into())
+    }
+
+    pub fn get(&self, point: impl Into<Point>) -> Option<&T> {
+        self.cells.get(&point.into())
+    }
+
+    pub fn get_mut(&mut self, point: impl Into<Point>) -> Option<&mut T> {
+        self.cells.get_mut(&point.into())
+    }
+
+    /// Sets the cell at `point`, returning its previous value.
+    pub fn insert(&mut self, point: impl Into<Point>, value: T) -> Option<T> {
+        let point = point.into();
+        self.include(point);
+        self.cells.insert(point, value)
+    }
+
+    /// The cell at `point`, first set with `value` if it isn't set yet.
+    pub fn get_or_insert_with(
+        &mut self,
+        point: impl Into<Point>,
+        value: impl FnOnce() -> T,
+    ) -> &mut T {
+        let point = point.into();
+        self.include(point);
+        self.cells.entry(point).or_insert_with(value)
+    }
+
+    /// Unsets the cell at `point`, returning its value. The bounds shrink when it was on their
+    /// edge, which takes a pass over every cell.
+    pub fn remove(&mut self, point: impl Into<Point>) -> Option<T> {
+        let point = point.into();
+        let value = self.cells.remove(&point)?;
+
+        let on_edge = self.bounds.is_some_and(|bounds| {
+            point.row == bounds.top_left.row
+                || point.row == bounds.bottom_right.row
+                || point.column == bounds.top_left.column
+                || point.column == bounds.bottom_right.column
+        });
+        if on_edge {
+            self.recompute_bounds();
+        }
+
+        Some(value)
+    }
+
+    fn include(&mut self, point: Point) {
+        self.bounds = Some(
+            self.bounds
+                .map_or(Bounds::point(point), |bounds| bounds.including(point)),
+        );
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = self
+            .cells
+            .keys()
+            .fold(None, |bounds: Option<Bounds>, &point| {
+                Some(bounds.map_or(Bounds::point(point), |bounds| bounds.including(point)))
+            });
+    }
+
+    /// The cell `(rows, columns)` away from `point`, if it is set.
+    pub fn get_offset(&self, point: impl Into<Point>, delta: (isize, isize)) -> Option<&T> {
+        self.get(point.into().offset(delta))
+    }
+
+    /// Set neighbors of `point`, with their points.
+    pub fn neighbors(
+        &self,
+        point: impl Into<Point>,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (Point, &T)> + '_ {
+        let point = point.into();
+        neighborhood.offsets().iter().filter_map(move |&delta| {
+            let neighbor = point.offset(delta);
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+
+    /// Points of every set cell, in no particular order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Every set cell with its point, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// Every set cell with its point, in no particular order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> + '_ {
+        self.cells.iter_mut().map(|(&point, value)| (point, value))
+    }
+
+    /// Keeps only the cells for which `keep` is true.
+    pub fn retain(&mut self, mut keep: impl FnMut(Point, &mut T) -> bool) {
+        self.cells.retain(|&point, value| keep(point, value));
+        self.recompute_bounds();
+    }
+
+    /// A dense grid of the bounds, whose index `(0, 0)` is the top left corner of the bounds.
+    /// Unset cells are `T::default()`.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone + Default,
+    {
+        let Some(bounds) = self.bounds else {
+            return Grid::new();
+        };
+
+        let mut grid = Grid::with_dimensions(bounds.width(), bounds.height());
+        for (&point, value) in &self.cells {
+            grid[bounds.grid_index(point)] = value.clone();
+        }
+        grid
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<T> IntoIterator for SparseGrid<T> {
+    type Item = (Point, T);
+    type IntoIter = hash_map::IntoIter<Point, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted<'a>(cells: impl Iterator<Item = (Point, &'a char)>) -> Vec<(i64, i64, char)> {
+        let mut cells = cells
+            .map(|(point, &c)| (point.row, point.column, c))
+            .collect::<Vec<_>>();
+        cells.sort();
+        cells
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+
+        grid.insert((0, 0), 'a');
+        grid.insert((-2, 3), 'b');
+        grid.insert((1, -1), 'c');
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            (Point::new(-2, -1), Point::new(1, 3)),
+            (bounds.top_left, bounds.bottom_right)
+        );
+        assert_eq!((5, 4), (bounds.width(), bounds.height()));
+
+        assert_eq!(None, grid.remove((5, 5)));
+        assert_eq!(Some('a'), grid.remove((0, 0)));
+        assert_eq!(Some(bounds), grid.bounds());
+        assert_eq!(Some('b'), grid.remove((-2, 3)));
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            (Point::new(1, -1), Point::new(1, -1)),
+            (bounds.top_left, bounds.bottom_right)
+        );
+
+        grid.retain(|_, _| false);
+        assert_eq!(None, grid.bounds());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid =
+            SparseGrid::from_grid(Grid::parse_with("#.#\n.#.", Some).unwrap(), |&c| c == '#');
+        assert_eq!(3, grid.len());
+
+        assert_eq!(
+            vec![(0, 0, '#'), (0, 2, '#')],
+            sorted(grid.neighbors((1, 1), Neighborhood::All))
+        );
+        assert_eq!(
+            Vec::<(i64, i64, char)>::new(),
+            sorted(grid.neighbors((1, 1), Neighborhood::Orthogonal))
+        );
+        assert_eq!(
+            vec![(0, 0, '#')],
+            sorted(grid.neighbors((-1, -1), Neighborhood::Diagonal))
+        );
+        assert_eq!(Some(&'#'), grid.get_offset((2, 3), (-2, -1)));
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let mut grid = SparseGrid::new();
+        for point in [(-1, -1), (0, 1), (1, 0)] {
+            *grid.get_or_insert_with(point, || 0) += 1;
+        }
+        *grid.get_or_insert_with((0, 1), || 0) += 1;
+
+        let dense = grid.to_grid();
+        assert_eq!(
+            vec![vec![1, 0, 0], vec![0, 0, 2], vec![0, 1, 0]],
+            dense.rows().map(<[u8]>::to_vec).collect::<Vec<_>>()
+        );
+        assert_eq!(Grid::new(), SparseGrid::<u8>::new().to_grid());
+    }
+}
@@ -0,0 +1,709 @@
+//! Borrowed rectangular regions of a [`Grid`], which share the grid's storage.
+
+use std::ops::{Index, IndexMut, Range};
+
+use super::{Grid, GridIndex, Neighborhood};
+
+/// Storage range and stride of the `width` by `height` region whose top left cell is
+/// `top_left`, in cells stored `stride` apart from one row to the next. An empty region has no
+/// storage, since its top left cell may be past the end of the cells.
+fn region(
+    stride: usize,
+    top_left: GridIndex,
+    width: usize,
+    height: usize,
+) -> (Range<usize>, usize) {
+    if width == 0 || height == 0 {
+        return (0..0, 0);
+    }
+    let start = top_left.row * stride + top_left.column;
+    (start..start + (height - 1) * stride + width, stride)
+}
+
+fn check_region(
+    (outer_width, outer_height): (usize, usize),
+    top_left: GridIndex,
+    width: usize,
+    height: usize,
+) {
+    assert!(
+        top_left.row + height <= outer_height && top_left.column + width <= outer_width,
+        "{width}x{height} region at {top_left:?} out of bounds for a {outer_width}x{outer_height} grid"
+    );
+}
+
+/// A read-only rectangular region of a grid, indexed from its own top left corner.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    cells: &'a [T],
+    /// Distance in `cells` between two vertically adjacent cells.
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    pub(super) fn new(cells: &'a [T], stride: usize, width: usize, height: usize) -> Self {
+        Self {
+            cells,
+            stride,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, index: impl Into<GridIndex>) -> bool {
+        let index = index.into();
+        index.row < self.height && index.column < self.width
+    }
+
+    fn position(&self, index: GridIndex) -> Option<usize> {
+        self.contains(index)
+            .then(|| index.row * self.stride + index.column)
+    }
+
+    pub fn get(&self, index: impl Into<GridIndex>) -> Option<&'a T> {
+        let cells = self.cells;
+        self.position(index.into()).map(|i| &cells[i])
+    }
+
+    fn cell(&self, index: GridIndex) -> &'a T {
+        self.get(index).unwrap_or_else(|| {
+            panic!(
+                "{index:?} out of bounds for a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+
+    /// The index `(rows, columns)` away from `index`, if it is in the grid.
+    pub fn offset(
+        &self,
+        index: impl Into<GridIndex>,
+        (rows, columns): (isize, isize),
+    ) -> Option<GridIndex> {
+        let index = index.into();
+        let moved = GridIndex {
+            row: index.row.checked_add_signed(rows)?,
+            column: index.column.checked_add_signed(columns)?,
+        };
+        self.contains(moved).then_some(moved)
+    }
+
+    /// The index `(rows, columns)` away from `index`, wrapping around the edges as if the grid
+    /// were a torus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't in the grid.
+    pub fn wrapping_offset(
+        &self,
+        index: impl Into<GridIndex>,
+        (rows, columns): (isize, isize),
+    ) -> GridIndex {
+        let index = index.into();
+        assert!(self.contains(index), "{index:?} out of bounds");
+
+        let wrap = |value: usize, delta: isize, len: usize| {
+            (value as isize + delta).rem_euclid(len as isize) as usize
+        };
+        GridIndex {
+            row: wrap(index.row, rows, self.height),
+            column: wrap(index.column, columns, self.width),
+        }
+    }
+
+    /// The cell `(rows, columns)` away from `index`, if it is in the grid.
+    pub fn get_offset(&self, index: impl Into<GridIndex>, delta: (isize, isize)) -> Option<&'a T> {
+        self.get(self.offset(index, delta)?)
+    }
+
+    /// Neighbors of `index` that are in the grid, with their indices.
+    pub fn neighbors(
+        &self,
+        index: impl Into<GridIndex>,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (GridIndex, &'a T)> + 'a {
+        let view = *self;
+        let index = index.into();
+        neighborhood.offsets().iter().filter_map(move |&delta| {
+            let neighbor = view.offset(index, delta)?;
+            Some((neighbor, view.get(neighbor)?))
+        })
+    }
+
+    /// Neighbors of `index`, with their indices, wrapping around the edges as if the grid were
+    /// a torus. On grids narrower or shorter than 3 cells, a cell can be its own neighbor or
+    /// have the same neighbor twice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't in the grid.
+    pub fn wrapping_neighbors(
+        &self,
+        index: impl Into<GridIndex>,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (GridIndex, &'a T)> + 'a {
+        let view = *self;
+        let index = index.into();
+        assert!(self.contains(index), "{index:?} out of bounds");
+        neighborhood.offsets().iter().map(move |&delta| {
+            let neighbor = view.wrapping_offset(index, delta);
+            (neighbor, view.cell(neighbor))
+        })
+    }
+
+    /// Indices of every cell, row by row.
+    pub fn indices(&self) -> impl Iterator<Item = GridIndex> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |row| (0..width).map(move |column| GridIndex { row, column }))
+    }
+
+    /// Every cell with its index, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (GridIndex, &'a T)> + 'a {
+        let view = *self;
+        self.indices().map(move |index| (index, view.cell(index)))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `row >= height`.
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.height, "row {row} out of bounds");
+        let cells = self.cells;
+        &cells[row * self.stride..row * self.stride + self.width]
+    }
+
+    /// Cells of `column`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column >= width`.
+    pub fn column(
+        &self,
+        column: usize,
+    ) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + 'a {
+        assert!(column < self.width, "column {column} out of bounds");
+        let view = *self;
+        (0..self.height).map(move |row| &view.cells[row * view.stride + column])
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + ExactSizeIterator + 'a {
+        let view = *self;
+        (0..self.height).map(move |row| view.row(row))
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<
+        Item = impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + 'a,
+    > + ExactSizeIterator
+           + 'a {
+        let view = *self;
+        (0..self.width).map(move |column| view.column(column))
+    }
+
+    /// Cells seen from `from` looking in the direction of `step`, a `(row, column)` delta, up to
+    /// the edge of the grid, with their indices. `from` itself isn't included; `.rev()` goes
+    /// from the edge back towards `from`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `(0, 0)`.
+    pub fn ray(&self, from: impl Into<GridIndex>, step: (isize, isize)) -> Ray<'a, T> {
+        Ray::new(*self, from.into(), step, 1)
+    }
+
+    /// Diagonals going down and to the right, from the one starting at the bottom left corner to
+    /// the one starting at the top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Ray<'a, T>> + 'a {
+        let view = *self;
+        let left = (0..self.height)
+            .rev()
+            .map(|row| GridIndex { row, column: 0 });
+        let top = (1..self.width).map(|column| GridIndex { row: 0, column });
+        left.chain(top)
+            .map(move |start| Ray::new(view, start, (1, 1), 0))
+    }
+
+    /// Diagonals going down and to the left, from the one starting at the top left corner to
+    /// the one starting at the bottom right corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Ray<'a, T>> + 'a {
+        let view = *self;
+        let top = (0..self.width).map(|column| GridIndex { row: 0, column });
+        let right = (1..self.height).filter_map(move |row| {
+            Some(GridIndex {
+                row,
+                column: view.width.checked_sub(1)?,
+            })
+        });
+        top.chain(right)
+            .map(move |start| Ray::new(view, start, (1, -1), 0))
+    }
+
+    /// The `width` by `height` region whose top left cell is `top_left`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region doesn't fit in this one.
+    pub fn view(
+        &self,
+        top_left: impl Into<GridIndex>,
+        width: usize,
+        height: usize,
+    ) -> GridView<'a, T> {
+        let top_left = top_left.into();
+        check_region((self.width, self.height), top_left, width, height);
+        let cells = self.cells;
+        let (range, stride) = region(self.stride, top_left, width, height);
+        GridView::new(&cells[range], stride, width, height)
+    }
+
+    /// A `width` by `height` grid whose cell at each index is the cell of this one at
+    /// `source(index)`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(GridIndex) -> GridIndex) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let view = *self;
+        let mut grid = Grid::new();
+        grid.width = width;
+        grid.height = height;
+        grid.inner = GridView::<T>::new(&[], width, width, height)
+            .indices()
+            .map(|index| view[source(index)].clone())
+            .collect();
+        grid
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.width, self.height, |index| index)
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |GridIndex { row, column }| {
+            GridIndex::new(column, row)
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |GridIndex { row, column }| {
+            GridIndex::new(height - 1 - column, row)
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |GridIndex { row, column }| {
+            GridIndex::new(column, width - 1 - row)
+        })
+    }
+
+    pub fn rotate_half(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        self.remap(width, height, |GridIndex { row, column }| {
+            GridIndex::new(height - 1 - row, width - 1 - column)
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(width, self.height, |GridIndex { row, column }| {
+            GridIndex::new(row, width - 1 - column)
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, height, |GridIndex { row, column }| {
+            GridIndex::new(height - 1 - row, column)
+        })
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.cell(index.into())
+    }
+}
+
+/// A mutable rectangular region of a grid, indexed from its own top left corner. Reads go
+/// through [`GridViewMut::as_view`].
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    cells: &'a mut [T],
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    pub(super) fn new(cells: &'a mut [T], stride: usize, width: usize, height: usize) -> Self {
+        Self {
+            cells,
+            stride,
+            width,
+            height,
+        }
+    }
+
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView::new(self.cells, self.stride, self.width, self.height)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, index: impl Into<GridIndex>) -> Option<&T> {
+        let i = self.as_view().position(index.into())?;
+        Some(&self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, index: impl Into<GridIndex>) -> Option<&mut T> {
+        let i = self.as_view().position(index.into())?;
+        Some(&mut self.cells[i])
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `row >= height`.
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.height, "row {row} out of bounds");
+        &mut self.cells[row * self.stride..row * self.stride + self.width]
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        let width = self.width;
+        self.cells
+            .chunks_mut(self.stride.max(1))
+            .take(self.height)
+            .map(move |row| &mut row[..width])
+    }
+
+    /// Every cell with its index, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (GridIndex, &mut T)> + '_ {
+        self.rows_mut().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter_mut()
+                .enumerate()
+                .map(move |(column, cell)| (GridIndex { row, column }, cell))
+        })
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for row in self.rows_mut() {
+            row.fill(value.clone());
+        }
+    }
+
+    /// The `width` by `height` region whose top left cell is `top_left`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region doesn't fit in this one.
+    pub fn view_mut(
+        &mut self,
+        top_left: impl Into<GridIndex>,
+        width: usize,
+        height: usize,
+    ) -> GridViewMut<'_, T> {
+        GridViewMut::new(self.cells, self.stride, self.width, self.height)
+            .into_region(top_left, width, height)
+    }
+
+    /// Narrows the view down to the `width` by `height` region whose top left cell is
+    /// `top_left`, keeping its lifetime.
+    pub(super) fn into_region(
+        self,
+        top_left: impl Into<GridIndex>,
+        width: usize,
+        height: usize,
+    ) -> GridViewMut<'a, T> {
+        let top_left = top_left.into();
+        check_region((self.width, self.height), top_left, width, height);
+        let (range, stride) = region(self.stride, top_left, width, height);
+        GridViewMut::new(&mut self.cells[range], stride, width, height)
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for GridViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: I) -> &Self::Output {
+        let i = self.as_view().position(index.into());
+        &self.cells[i.expect("index out of bounds")]
+    }
+}
+
+impl<T, I: Into<GridIndex>> IndexMut<I> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let i = self.as_view().position(index.into());
+        &mut self.cells[i.expect("index out of bounds")]
+    }
+}
+
+/// Cells on a straight line through a grid, with their indices. See [`GridView::ray`].
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    view: GridView<'a, T>,
+    start: GridIndex,
+    step: (isize, isize),
+    /// Range of the multiples of `step` away from `start` still to be visited.
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Ray<'a, T> {
+    /// The ray through the cells `first`, `first + 1`, ... steps away from `start`, up to the
+    /// edge of `view`.
+    fn new(view: GridView<'a, T>, start: GridIndex, step: (isize, isize), first: usize) -> Self {
+        assert!(step != (0, 0), "a ray needs a non-zero step");
+
+        let steps = |value: usize, delta: isize, len: usize| match delta.signum() {
+            0 => usize::MAX,
+            1 => (len - 1 - value) / delta.unsigned_abs(),
+            _ => value / delta.unsigned_abs(),
+        };
+        let back = if view.contains(start) {
+            steps(start.row, step.0, view.height).min(steps(start.column, step.1, view.width)) + 1
+        } else {
+            first
+        };
+
+        Self {
+            view,
+            start,
+            step,
+            front: first,
+            back,
+        }
+    }
+
+    fn at(&self, k: usize) -> (GridIndex, &'a T) {
+        let k = k as isize;
+        let index = GridIndex {
+            row: (self.start.row as isize + k * self.step.0) as usize,
+            column: (self.start.column as isize + k * self.step.1) as usize,
+        };
+        (index, self.view.get(index).expect("rays stay in the grid"))
+    }
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (GridIndex, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            self.front += 1;
+            self.at(self.front - 1)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back.saturating_sub(self.front);
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Ray<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.at(self.back)
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Ray<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::parse_with("123\n456", |c| c.to_digit(10).map(|d| d as u8)).unwrap()
+    }
+
+    fn rows(grid: &Grid<u8>) -> Vec<Vec<u8>> {
+        grid.rows().map(<[u8]>::to_vec).collect()
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = grid();
+
+        assert_eq!(
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]],
+            rows(&grid.transpose())
+        );
+        assert_eq!(
+            vec![vec![4, 1], vec![5, 2], vec![6, 3]],
+            rows(&grid.rotate_clockwise())
+        );
+        assert_eq!(
+            vec![vec![3, 6], vec![2, 5], vec![1, 4]],
+            rows(&grid.rotate_counterclockwise())
+        );
+        assert_eq!(
+            vec![vec![6, 5, 4], vec![3, 2, 1]],
+            rows(&grid.rotate_half())
+        );
+        assert_eq!(
+            vec![vec![3, 2, 1], vec![6, 5, 4]],
+            rows(&grid.flip_horizontal())
+        );
+        assert_eq!(
+            vec![vec![4, 5, 6], vec![1, 2, 3]],
+            rows(&grid.flip_vertical())
+        );
+
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counterclockwise());
+        assert_eq!(
+            grid.rotate_half(),
+            grid.rotate_clockwise().rotate_clockwise()
+        );
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = Grid::parse_with("1234\n5678\n9abc", |c| c.to_digit(16)).unwrap();
+        let view = grid.view((1, 1), 3, 2);
+
+        assert_eq!((3, 2), (view.width(), view.height()));
+        assert_eq!(6, view[(0, 0)]);
+        assert_eq!(Some(&12), view.get((1, 2)));
+        assert_eq!(None, view.get((0, 3)));
+        assert_eq!(
+            vec![&[6, 7, 8][..], &[10, 11, 12]],
+            view.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![8, 12], view.column(2).copied().collect::<Vec<_>>());
+        assert_eq!(
+            vec![7, 10, 12],
+            view.neighbors((1, 1), Neighborhood::Orthogonal)
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![7, 8],
+            view.ray((0, 0), (0, 1))
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>()
+        );
+
+        let inner = view.view((1, 1), 2, 1);
+        assert_eq!(
+            vec![11, 12],
+            inner.iter().map(|(_, &v)| v).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![vec![6, 10], vec![7, 11], vec![8, 12]], {
+            let transposed = view.transpose();
+            transposed.rows().map(<[u32]>::to_vec).collect::<Vec<_>>()
+        });
+    }
+
+    #[test]
+    fn test_view_mut() {
+        let mut grid = Grid::parse_with("1234\n5678\n9abc", |c| c.to_digit(16)).unwrap();
+
+        let mut view = grid.view_mut((1, 1), 2, 2);
+        view[(0, 0)] = 0;
+        *view.get_mut((1, 1)).unwrap() = 0;
+        assert_eq!(None, view.get_mut((2, 0)));
+        view.view_mut((0, 1), 1, 2).fill(15);
+        for (index, cell) in view.iter_mut() {
+            if index.column == 0 {
+                *cell += 1;
+            }
+        }
+
+        assert_eq!(
+            "1234\n51f8\n9bfc",
+            grid.render(|&d| char::from_digit(d, 16).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_view_empty() {
+        let mut grid = Grid::parse_with("1234\n5678\n9abc", |c| c.to_digit(16)).unwrap();
+
+        let corner = grid.view((3, 4), 0, 0);
+        assert_eq!((0, 0), (corner.width(), corner.height()));
+        assert_eq!(0, corner.rows().len());
+        let bottom = grid.view((3, 1), 2, 0);
+        assert_eq!(0, bottom.rows().len());
+        assert_eq!(2, bottom.columns().len());
+        let right = grid.view((0, 4), 0, 3);
+        assert_eq!(
+            vec![0; 3],
+            right.rows().map(<[u32]>::len).collect::<Vec<_>>()
+        );
+        assert_eq!(None, right.get((0, 0)));
+
+        let mut corner = grid.view_mut((3, 4), 0, 0);
+        assert_eq!(0, corner.iter_mut().count());
+        grid.view_mut((3, 1), 2, 0).fill(0);
+        assert_eq!(
+            "1234\n5678\n9abc",
+            grid.render(|&d| char::from_digit(d, 16).unwrap())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_view_out_of_bounds() {
+        grid().view((1, 1), 3, 1);
+    }
+}
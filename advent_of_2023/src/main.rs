@@ -0,0 +1,13 @@
+use std::process::ExitCode;
+
+use advent_of_2023::registry::CALENDAR;
+
+fn main() -> ExitCode {
+    match adventofrust::cli::main(&CALENDAR, std::env::args().skip(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Index of every puzzle solved in this crate.
+
+use adventofrust::{
+    input::InputDir,
+    registry::{Calendar, Puzzle},
+    scaffold::Layout,
+};
+
+use crate::{day1, day10, day2, day3, day4, day5, day6, day7, day8, day9};
+
+pub const INPUT_DIR: InputDir = InputDir {
+    root: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    pattern: "day {day}/input.txt",
+};
+
+/// All puzzles, ordered by day.
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day1::CalibrationDocument>(1, "day1", "Trebuchet?!"),
+    Puzzle::new::<day2::Record>(2, "day2", "Cube Conundrum"),
+    Puzzle::new::<day3::Schematic>(3, "day3", "Gear Ratios"),
+    Puzzle::new::<day4::Cards>(4, "day4", "Scratchcards"),
+    Puzzle::new::<day5::Almanac>(5, "day5", "If You Give A Seed A Fertilizer"),
+    Puzzle::new::<day6::Races>(6, "day6", "Wait For It"),
+    Puzzle::new::<day7::Game>(7, "day7", "Camel Cards"),
+    Puzzle::new::<day8::Network>(8, "day8", "Haunted Wasteland"),
+    Puzzle::new::<day9::Report>(9, "day9", "Mirage Maintenance"),
+    Puzzle::new::<day10::Sketch>(10, "day10", "Pipe Maze"),
+];
+
+pub const CALENDAR: Calendar = Calendar {
+    year: 2023,
+    puzzles: PUZZLES,
+    input_dir: INPUT_DIR,
+    layout: Layout {
+        root: env!("CARGO_MANIFEST_DIR"),
+        lib: "advent_of_2023",
+        binaries: true,
+    },
+};
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use adventofrust::answers;
+
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let manifest = fs::read_to_string(root.join("answers.txt")).unwrap();
+        let manifest = answers::parse_manifest(&manifest).unwrap();
+
+        let failures = answers::check(PUZZLES, &INPUT_DIR, &manifest);
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_puzzles_sorted_and_unique() {
+        assert!(PUZZLES.windows(2).all(|w| w[0].day < w[1].day));
+    }
+}
@@ -0,0 +1,235 @@
+//! Manifest of the expected answers for the puzzle inputs, and a check of the registered
+//! solutions against it.
+//!
+//! The manifest has one expected answer per line, as the day, the input file relative to the
+//! input directory, the part and the answer, separated by `|`. Newlines in answers are written
+//! as `\n`; blank lines and lines starting with `#` are ignored.
+
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::{input::InputDir, registry::Puzzle, solution::Part};
+
+/// Longest a part may take on an input before the check gives up on it, so that a part that
+/// never finishes, and would hang `run all` and `time` as well, fails the check instead.
+const TIME_LIMIT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+impl TryFrom<&str> for Expected {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let ferrstr = || format!("invalid expected answer: {}", s);
+
+        let mut fields = s.split('|').map(str::trim);
+        let day = fields.next().ok_or_else(ferrstr)?;
+        let input = fields.next().ok_or_else(ferrstr)?;
+        let part = fields.next().ok_or_else(ferrstr)?;
+        let answer = fields.next().ok_or_else(ferrstr)?;
+
+        if fields.next().is_some() || input.is_empty() || answer.is_empty() {
+            return Err(ferrstr());
+        }
+
+        Ok(Self {
+            day: day.parse().map_err(|_| ferrstr())?,
+            input: input.to_owned(),
+            part: Part::try_from(part)?,
+            answer: answer.replace("\\n", "\n"),
+        })
+    }
+}
+
+pub fn parse_manifest(s: &str) -> Result<Vec<Expected>, String> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(Expected::try_from)
+        .collect()
+}
+
+/// Paths, relative to `dir`, of all the `.txt` files under `dir`.
+fn list_inputs(dir: &Path) -> Result<BTreeSet<PathBuf>, String> {
+    fn aux(dir: &Path, prefix: &Path, out: &mut BTreeSet<PathBuf>) -> Result<(), String> {
+        let entries =
+            fs::read_dir(dir).map_err(|e| format!("failed to read {}: {}", dir.display(), e))?;
+
+        for entry in entries {
+            let entry = entry.map_err(|e| e.to_string())?;
+            let path = entry.path();
+            let relative = prefix.join(entry.file_name());
+
+            if path.is_dir() {
+                aux(&path, &relative, out)?;
+            } else if path.extension().is_some_and(|ext| ext == "txt") {
+                out.insert(relative);
+            }
+        }
+
+        Ok(())
+    }
+
+    let mut out = BTreeSet::new();
+    aux(dir, Path::new(""), &mut out)?;
+    Ok(out)
+}
+
+/// Solves `part` of `puzzle` on `input` on another thread, giving up after [`TIME_LIMIT`].
+fn solve_in_time(puzzle: Puzzle, input: &str, part: Part) -> Result<String, String> {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_owned();
+    thread::spawn(move || {
+        let answer = puzzle
+            .parse(&input)
+            .and_then(|solution| solution.solve(part));
+        let _ = sender.send(answer.map_err(|e| e.to_string()));
+    });
+
+    match receiver.recv_timeout(TIME_LIMIT) {
+        Ok(answer) => answer,
+        Err(RecvTimeoutError::Timeout) => {
+            Err(format!("no answer after {} s", TIME_LIMIT.as_secs()))
+        }
+        Err(RecvTimeoutError::Disconnected) => Err("panicked".to_owned()),
+    }
+}
+
+/// Runs every puzzle on each input file of its day listed in `manifest`, and describes every
+/// answer that doesn't match the expected one or takes longer than [`TIME_LIMIT`]. Input files
+/// under `input_dir` without any expected answer, and solved parts without an expected answer
+/// on the puzzle's own input when it is on disk, are reported as well.
+pub fn check(puzzles: &[Puzzle], input_dir: &InputDir, manifest: &[Expected]) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    let mut unchecked_inputs = match list_inputs(input_dir.root()) {
+        Ok(inputs) => inputs,
+        Err(e) => return vec![e],
+    };
+
+    let mut inputs: Vec<(u8, &str)> = Vec::new();
+    for expected in manifest {
+        if !inputs.contains(&(expected.day, &expected.input)) {
+            inputs.push((expected.day, &expected.input));
+        }
+    }
+
+    for &(day, input) in &inputs {
+        unchecked_inputs.remove(Path::new(input));
+
+        let path = input_dir.root().join(input);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => {
+                failures.push(format!("failed to read {}: {}", path.display(), e));
+                continue;
+            }
+        };
+
+        let day_puzzles = puzzles.iter().filter(|puzzle| puzzle.day == day);
+        let expected_answers = manifest
+            .iter()
+            .filter(|expected| expected.day == day && expected.input == input);
+
+        for puzzle in day_puzzles {
+            for expected in expected_answers.clone() {
+                let part = usize::from(expected.part);
+                if !puzzle.is_solved(expected.part) {
+                    failures.push(format!("{} has no solution for part {}", puzzle.name, part));
+                    continue;
+                }
+
+                match solve_in_time(*puzzle, &text, expected.part) {
+                    Ok(answer) if answer == expected.answer => (),
+                    Ok(answer) => failures.push(format!(
+                        "{} on {}, part {}: expected {}, got {}",
+                        puzzle.name, input, part, expected.answer, answer
+                    )),
+                    Err(e) => failures.push(format!(
+                        "{} on {}, part {}: {}",
+                        puzzle.name, input, part, e
+                    )),
+                }
+            }
+        }
+    }
+
+    for input in unchecked_inputs {
+        failures.push(format!("no expected answers for {}", input.display()));
+    }
+
+    for puzzle in puzzles {
+        let path = puzzle.input_path(input_dir);
+        let input = path.strip_prefix(input_dir.root()).unwrap_or(&path);
+        // Without its own input, the puzzle can only be checked on the samples of its day.
+        let has_input = path.exists();
+        for part in Part::MEMBERS {
+            let is_expected = manifest.iter().any(|expected| {
+                expected.day == puzzle.day
+                    && (Path::new(&expected.input) == input || !has_input)
+                    && expected.part == part
+            });
+            if puzzle.is_solved(part) && !is_expected {
+                failures.push(format!(
+                    "no expected answer for {}, part {} on {}",
+                    puzzle.name,
+                    usize::from(part),
+                    input.display()
+                ));
+            }
+        }
+    }
+
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = "# comment
+
+7 | no_space_left_on_device.txt | 2 | 3866390
+10 | day 10/0.txt | 1 | ##..\\n..##";
+
+        assert_eq!(
+            Ok(vec![
+                Expected {
+                    day: 7,
+                    input: "no_space_left_on_device.txt".to_owned(),
+                    part: Part::Two,
+                    answer: "3866390".to_owned(),
+                },
+                Expected {
+                    day: 10,
+                    input: "day 10/0.txt".to_owned(),
+                    part: Part::One,
+                    answer: "##..\n..##".to_owned(),
+                },
+            ]),
+            parse_manifest(manifest)
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_errors() {
+        assert!(parse_manifest("7 | input.txt | 2").is_err());
+        assert!(parse_manifest("7 | input.txt | 3 | 42").is_err());
+        assert!(parse_manifest("seven | input.txt | 2 | 42").is_err());
+        assert!(parse_manifest("7 | input.txt | 2 | 42 | 43").is_err());
+        assert!(parse_manifest("7 | | 2 | 42").is_err());
+    }
+}
@@ -1,27 +1,9 @@
 //! Day 24: Blizzard Basin
 //! https://adventofcode.com/2022/day/24
 
-use std::{
-    collections::BTreeSet,
-    fmt::Display,
-    fs,
-    ops::{Index, IndexMut},
-    str::FromStr,
-};
-
-trait CheckedDec {
-    fn checked_dec(self) -> Self;
-}
+use std::{collections::HashSet, fmt::Display, iter::once, str::FromStr};
 
-impl CheckedDec for usize {
-    fn checked_dec(self) -> Self {
-        if self == 0 {
-            self
-        } else {
-            self - 1
-        }
-    }
-}
+use crate::{error::Error, solution::Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum HazzardMovement {
@@ -57,164 +39,240 @@ impl From<HazzardMovement> for char {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Coordinate<const WIDTH: usize, const HEIGHT: usize> {
+pub struct Coordinate {
     x: usize,
     y: usize,
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize> Coordinate<WIDTH, HEIGHT> {
-    const MAXX: usize = WIDTH - 1;
-    const MAXY: usize = HEIGHT - 1;
+impl Coordinate {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
 
-    pub const MAX: Self = Self {
-        x: Self::MAXX,
-        y: Self::MAXY,
-    };
+impl Display for Coordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
 
-    pub const MIN: Self = Self { x: 0, y: 0 };
+/// Largest width or height of a valley, so that a row or column of it fits in a `u128`.
+const MAX_SIDE: usize = u128::BITS as usize;
 
-    pub fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
+/// Mask of the `bits` lowest bits.
+fn low_bits(bits: usize) -> u128 {
+    if bits == MAX_SIDE {
+        !0
+    } else {
+        (1 << bits) - 1
     }
+}
 
-    pub fn new_checked(x: usize, y: usize) -> Option<Self> {
-        if x > Self::MAXX || y > Self::MAXY {
-            None
-        } else {
-            Some(Self { x, y })
-        }
+/// Rotates the `bits` lowest bits of `mask` by `n` towards the high bits.
+fn rotate(mask: u128, n: usize, bits: usize) -> u128 {
+    match n % bits {
+        0 => mask,
+        n => (mask << n | mask >> (bits - n)) & low_bits(bits),
     }
+}
 
-    fn get_neighbors(&self) -> [Option<Self>; 4] {
-        [
-            if self.x == 0 {
-                None
-            } else {
-                Some(Self {
-                    x: self.x - 1,
-                    y: self.y,
-                })
-            },
-            if self.y == 0 {
-                None
-            } else {
-                Some(Self {
-                    x: self.x,
-                    y: self.y - 1,
-                })
-            },
-            if self.x == WIDTH - 1 {
-                None
-            } else {
-                Some(Self {
-                    x: self.x + 1,
-                    y: self.y,
-                })
-            },
-            if self.y == HEIGHT - 1 {
-                None
-            } else {
-                Some(Self {
-                    x: self.x,
-                    y: self.y + 1,
-                })
-            },
-        ]
-    }
-
-    fn move_hazzard(self, m: HazzardMovement) -> Self {
-        match m {
-            HazzardMovement::Up => match self.y {
-                0 => Self {
-                    y: HEIGHT - 1,
-                    ..self
-                },
-                y => Self { y: y - 1, ..self },
-            },
-            HazzardMovement::Down => {
-                let y = self.y + 1;
-                if y == HEIGHT {
-                    Self { y: 0, ..self }
-                } else {
-                    Self { y, ..self }
-                }
-            }
-            HazzardMovement::Left => match self.x {
-                0 => Self {
-                    x: WIDTH - 1,
-                    ..self
-                },
-                x => Self { x: x - 1, ..self },
-            },
-            HazzardMovement::Right => {
-                let x = self.x + 1;
-                if x == WIDTH {
-                    Self { x: 0, ..self }
-                } else {
-                    Self { x, ..self }
-                }
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Tiles taken by blizzards at every minute of their period, as one bitmask per row with a bit
+/// per column. Blizzards come back to where they started every `lcm(width, height)` minutes.
+#[derive(Debug, Clone)]
+struct Blizzards {
+    period: usize,
+    occupied: Vec<Vec<u128>>,
+}
+
+impl Blizzards {
+    fn new(width: usize, height: usize, hazzards: &[(HazzardMovement, Coordinate)]) -> Self {
+        // Blizzards never leave their row or column, so each row holds the blizzards moving
+        // left or right in it, and each column the blizzards moving up or down in it.
+        let mut rights = vec![0u128; height];
+        let mut lefts = vec![0u128; height];
+        let mut downs = vec![0u128; width];
+        let mut ups = vec![0u128; width];
+        for &(movement, Coordinate { x, y }) in hazzards {
+            match movement {
+                HazzardMovement::Right => rights[y] |= 1 << x,
+                HazzardMovement::Left => lefts[y] |= 1 << x,
+                HazzardMovement::Down => downs[x] |= 1 << y,
+                HazzardMovement::Up => ups[x] |= 1 << y,
             }
         }
+
+        let period = width / gcd(width, height) * height;
+        let occupied = (0..period)
+            .map(|minute| {
+                let downs = downs.iter().map(|&column| rotate(column, minute, height));
+                let ups = ups
+                    .iter()
+                    .map(|&column| rotate(column, height - minute % height, height));
+                let columns = downs.zip(ups).map(|(d, u)| d | u).collect::<Vec<_>>();
+
+                (0..height)
+                    .map(|y| {
+                        let vertical = columns
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, &column)| column >> y & 1 == 1)
+                            .fold(0, |row, (x, _)| row | 1 << x);
+                        rotate(rights[y], minute, width)
+                            | rotate(lefts[y], width - minute % width, width)
+                            | vertical
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self { period, occupied }
+    }
+
+    /// Rows of the tiles taken at `minute`.
+    fn at(&self, minute: usize) -> &[u128] {
+        &self.occupied[minute % self.period]
     }
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize> Display for Coordinate<WIDTH, HEIGHT> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
+/// Minutes taken by each leg of a journey through the valley.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Journey {
+    legs: Vec<usize>,
+}
+
+impl Journey {
+    pub fn legs(&self) -> &[usize] {
+        &self.legs
+    }
+
+    pub fn total(&self) -> usize {
+        self.legs.iter().sum()
     }
 }
 
+/// The valley inside the walls. Coordinates are those of the tiles inside the walls, so the
+/// expedition steps in at [`Board::start`] and out from [`Board::goal`].
 #[derive(Debug, Clone)]
-pub struct Board<const WIDTH: usize, const HEIGHT: usize> {
-    count: usize,
-    hazzards: Vec<(HazzardMovement, Coordinate<WIDTH, HEIGHT>)>,
+pub struct Board {
+    width: usize,
+    height: usize,
+    start: Coordinate,
+    goal: Coordinate,
+    hazzards: Vec<(HazzardMovement, Coordinate)>,
+    blizzards: Blizzards,
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize> Board<WIDTH, HEIGHT> {
-    fn tick(&mut self) {
-        for &mut (movement, ref mut coord) in self.hazzards.iter_mut() {
-            *coord = coord.move_hazzard(movement);
-        }
-        self.count += 1;
+impl Board {
+    pub fn width(&self) -> usize {
+        self.width
     }
 
-    fn tick_filter(&mut self, next_moves: &mut BTreeSet<Coordinate<WIDTH, HEIGHT>>) {
-        for &mut (movement, ref mut coord) in self.hazzards.iter_mut() {
-            *coord = coord.move_hazzard(movement);
-            next_moves.remove(coord);
-        }
-        self.count += 1;
+    pub fn height(&self) -> usize {
+        self.height
     }
 
-    pub fn solve(
-        &mut self,
-        start_coord: Coordinate<WIDTH, HEIGHT>,
-        end_coord: Coordinate<WIDTH, HEIGHT>,
-    ) -> usize {
-        let mut next_moves: BTreeSet<Coordinate<WIDTH, HEIGHT>> = BTreeSet::new();
+    /// The tile right below the entrance in the top wall.
+    pub fn start(&self) -> Coordinate {
+        self.start
+    }
+
+    /// The tile right above the exit in the bottom wall.
+    pub fn goal(&self) -> Coordinate {
+        self.goal
+    }
+
+    pub fn contains(&self, coord: Coordinate) -> bool {
+        coord.x < self.width && coord.y < self.height
+    }
+
+    /// Whether `coord` is next to the entrance or the exit, where the expedition can step out
+    /// of the valley and wait.
+    fn is_gap(&self, coord: Coordinate) -> bool {
+        coord == self.start || coord == self.goal
+    }
+
+    /// Minute at which the expedition, setting off from `from` at minute `departure`, is done
+    /// with `to` at the earliest. It sets off from outside the valley next to `from` if `from`
+    /// is the start or the goal, where it can wait as long as it needs, and from `from` itself
+    /// otherwise. It is done with the start or the goal once it steps out of the valley from
+    /// there, and with any other tile once it is on it.
+    fn leg(&self, from: Coordinate, to: Coordinate, departure: usize) -> Result<usize, Error> {
+        let full = low_bits(self.width);
+        let outside = self.is_gap(from);
+        // Tiles the expedition can be on at `minute`, as one bitmask per row.
+        let mut reachable = vec![0u128; self.height];
+        if !outside {
+            reachable[from.y] = 1 << from.x;
+        }
+        let mut seen = HashSet::new();
+        let mut minute = departure;
 
         loop {
-            // println!("Iteration: {}", self.count);
-            // println!("{}", self);
-            // println!();
-
-            for player_location in next_moves.clone() {
-                if player_location == end_coord {
-                    self.tick();
-                    return self.count;
-                }
+            if reachable[to.y] >> to.x & 1 == 1 {
+                return Ok(if self.is_gap(to) { minute + 1 } else { minute });
+            }
 
-                next_moves.extend(player_location.get_neighbors().into_iter().flatten());
+            // Blizzards repeat every period, so the search is over once the tiles reachable at
+            // the same point of the period repeat.
+            if (minute - departure).is_multiple_of(self.blizzards.period)
+                && !seen.insert(reachable.clone())
+            {
+                return Err(Error::new(format!("no way from {} to {}", from, to)));
             }
 
-            next_moves.insert(start_coord);
+            minute += 1;
+            let occupied = self.blizzards.at(minute);
+            reachable = (0..self.height)
+                .map(|y| {
+                    let row = reachable[y];
+                    let mut next = row | row << 1 | row >> 1;
+                    if y > 0 {
+                        next |= reachable[y - 1];
+                    }
+                    if y + 1 < self.height {
+                        next |= reachable[y + 1];
+                    }
+                    if outside && y == from.y {
+                        next |= 1 << from.x;
+                    }
+                    next & full & !occupied[y]
+                })
+                .collect();
+        }
+    }
 
-            self.tick_filter(&mut next_moves);
+    /// Crosses the valley between each waypoint and the next, starting at minute 0 outside the
+    /// valley next to the first one. Legs to [`Board::start`] or [`Board::goal`] end by stepping
+    /// out of the valley, where the expedition can wait as long as it needs before the next
+    /// leg. Legs to any other tile end on it, and the next leg sets off from there right away.
+    pub fn journey(&self, waypoints: &[Coordinate]) -> Result<Journey, Error> {
+        if let Some(coord) = waypoints.iter().find(|&&coord| !self.contains(coord)) {
+            return Err(Error::new(format!(
+                "{} is outside of the {}x{} valley",
+                coord, self.width, self.height
+            )));
         }
+
+        let mut legs = Vec::new();
+        let mut minute = 0;
+        for pair in waypoints.windows(2) {
+            let arrival = self.leg(pair[0], pair[1], minute)?;
+            legs.push(arrival - minute);
+            minute = arrival;
+        }
+
+        Ok(Journey { legs })
     }
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize> Display for Board<WIDTH, HEIGHT> {
+impl Display for Board {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         #[derive(Debug, Clone, Copy)]
         enum DisplayTile {
@@ -238,10 +296,10 @@ impl<const WIDTH: usize, const HEIGHT: usize> Display for Board<WIDTH, HEIGHT> {
             }
         }
 
-        let mut grid = [[DisplayTile::default(); WIDTH]; HEIGHT];
+        let mut grid = vec![vec![DisplayTile::default(); self.width]; self.height];
 
         for &(movement, coord) in self.hazzards.iter() {
-            let tile = &mut grid[coord];
+            let tile = &mut grid[coord.y][coord.x];
             *tile = match tile {
                 DisplayTile::Count(0) => DisplayTile::Hazzard(movement),
                 DisplayTile::Hazzard(_) => DisplayTile::Count(2),
@@ -249,83 +307,169 @@ impl<const WIDTH: usize, const HEIGHT: usize> Display for Board<WIDTH, HEIGHT> {
             };
         }
 
-        let grid = grid
-            .map(|row| row.into_iter().map(char::from).collect::<String>())
+        let wall = |gap: usize| -> String {
+            (0..self.width + 2)
+                .map(|x| if x == gap + 1 { '.' } else { '#' })
+                .collect()
+        };
+        let rows = grid.into_iter().map(|row| {
+            once('#')
+                .chain(row.into_iter().map(char::from))
+                .chain(once('#'))
+        });
+
+        let grid = once(wall(self.start.x))
+            .chain(rows.map(String::from_iter))
+            .chain(once(wall(self.goal.x)))
+            .collect::<Vec<_>>()
             .join("\n");
 
         f.write_str(&grid)
     }
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize> FromStr for Board<WIDTH, HEIGHT> {
-    type Err = ();
+/// Checks that `line` is `width` characters long, pointing at the first extra character or
+/// the end of the line if it isn't.
+fn check_width(line: &str, width: usize) -> Result<(), Error> {
+    match line.char_indices().nth(width) {
+        Some((i, ch)) => Err(Error::at(
+            &line[i..i + ch.len_utf8()],
+            format!("beyond the wall of a valley {} tiles wide", width),
+        )),
+        None if line.chars().count() < width => Err(Error::at(&line[line.len()..], "expected '#'")),
+        None => Ok(()),
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let hazzards = s
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.chars().enumerate().filter_map(move |(x, ch)| {
-                    let Some(coord) = Coordinate::new_checked(x, y) else { return Some(Err(())); };
-                    let movement = HazzardMovement::try_from(ch).ok()?;
-                    Some(Ok((movement, coord)))
-                })
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(Self { count: 0, hazzards })
+/// Finds the single gap in the top or bottom wall, as an x coordinate inside the walls.
+fn find_gap(line: &str) -> Result<usize, Error> {
+    let width = line.chars().count();
+    let mut gap = None;
+
+    for (column, (i, ch)) in line.char_indices().enumerate() {
+        let snippet = &line[i..i + ch.len_utf8()];
+        match ch {
+            '#' => (),
+            '.' if gap.is_some() => {
+                return Err(Error::at(snippet, "expected a single gap in the wall"))
+            }
+            '.' if column != 0 && column != width - 1 => gap = Some(column - 1),
+            _ => return Err(Error::at(snippet, "expected '#' or a gap '.'")),
+        }
     }
+
+    gap.ok_or_else(|| Error::at(line, "expected a gap in the wall"))
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize, T> Index<Coordinate<WIDTH, HEIGHT>>
-    for [[T; WIDTH]; HEIGHT]
-{
-    type Output = T;
+impl FromStr for Board {
+    type Err = Error;
+
+    /// Parses the valley with its walls, as in the puzzle input.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+        let [top, rows @ .., bottom] = lines.as_slice() else {
+            return Err(Error::at(s, "expected walls above and below the valley"));
+        };
+        if rows.is_empty() {
+            return Err(Error::at(bottom, "expected the valley between the walls"));
+        }
+
+        let outer_width = top.chars().count();
+        if outer_width < 3 {
+            return Err(Error::at(top, "expected the valley between the walls"));
+        }
+        let (width, height) = (outer_width - 2, rows.len());
+        if width > MAX_SIDE || height > MAX_SIDE {
+            return Err(Error::new(format!(
+                "valleys larger than {} tiles on a side aren't supported",
+                MAX_SIDE
+            )));
+        }
+
+        let mut hazzards = Vec::new();
+        for (y, line) in rows.iter().enumerate() {
+            check_width(line, outer_width)?;
+            for (column, (i, ch)) in line.char_indices().enumerate() {
+                let snippet = &line[i..i + ch.len_utf8()];
+                if column == 0 || column == outer_width - 1 {
+                    if ch != '#' {
+                        return Err(Error::at(snippet, "expected '#'"));
+                    }
+                    continue;
+                }
+
+                match HazzardMovement::try_from(ch) {
+                    Ok(movement) => hazzards.push((movement, Coordinate::new(column - 1, y))),
+                    Err(()) if ch == '.' => (),
+                    Err(()) => {
+                        return Err(Error::at(snippet, "expected '.', '>', '^', '<' or 'v'"))
+                    }
+                }
+            }
+        }
 
-    fn index(&self, index: Coordinate<WIDTH, HEIGHT>) -> &Self::Output {
-        self.index(index.y).index(index.x)
+        check_width(top, outer_width)?;
+        check_width(bottom, outer_width)?;
+        let start = Coordinate::new(find_gap(top)?, 0);
+        let goal = Coordinate::new(find_gap(bottom)?, height - 1);
+
+        Ok(Self {
+            width,
+            height,
+            start,
+            goal,
+            blizzards: Blizzards::new(width, height, &hazzards),
+            hazzards,
+        })
     }
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize, T> IndexMut<Coordinate<WIDTH, HEIGHT>>
-    for [[T; WIDTH]; HEIGHT]
-{
-    fn index_mut(&mut self, index: Coordinate<WIDTH, HEIGHT>) -> &mut Self::Output {
-        self.index_mut(index.y).index_mut(index.x)
+impl Solution for Board {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input.parse()
     }
-}
 
-#[allow(dead_code)]
-pub fn main() -> Result<(), String> {
-    const INPUT_FILENAME: &str = "input/blizzard_basin.txt";
-    let input = fs::read_to_string(INPUT_FILENAME).map_err(|e| e.to_string())?;
-    let mut board: Board<100, 35> = input.parse().expect("We choose the correct input here.");
-    println!("{}", board.solve(Coordinate::MIN, Coordinate::MAX));
-    println!("{}", board.solve(Coordinate::MAX, Coordinate::MIN));
-    println!("{}", board.solve(Coordinate::MIN, Coordinate::MAX));
+    fn part1(&self) -> Result<usize, Error> {
+        Ok(self.journey(&[self.start, self.goal])?.total())
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<usize, Error> {
+        let journey = self.journey(&[self.start, self.goal, self.start, self.goal])?;
+        Ok(journey.total())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT_0: &str = ">>.<^<
-.<..<<
->v.><>
-<^v^^>";
+    const TEST_INPUT_0: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
 
-    const TEST_INPUT_1: &str = ".....
->....
-...v.";
+    const TEST_INPUT_1: &str = "#.#####
+#.....#
+#>....#
+#...v.#
+#####.#";
 
-    const TEST_INPUT_2: &str = "..\n..";
+    const TEST_INPUT_2: &str = "#.##\n#..#\n#..#\n##.#";
 
     #[test]
     fn test_board_from_str() {
-        let board: Board<6, 4> = TEST_INPUT_0.parse().unwrap();
+        let board: Board = TEST_INPUT_0.parse().unwrap();
+
+        assert_eq!((6, 4), (board.width(), board.height()));
+        assert_eq!(Coordinate::new(0, 0), board.start());
+        assert_eq!(Coordinate::new(5, 3), board.goal());
 
-        let expected_hazzards: [(HazzardMovement, Coordinate<6, 4>); 19] = [
+        let expected_hazzards: [(HazzardMovement, Coordinate); 19] = [
             (HazzardMovement::Right, Coordinate::new(0, 0)),
             (HazzardMovement::Right, Coordinate::new(1, 0)),
             (HazzardMovement::Left, Coordinate::new(3, 0)),
@@ -361,33 +505,111 @@ mod tests {
                 hazzard
             );
         }
+
+        assert_eq!(TEST_INPUT_0, board.to_string());
+    }
+
+    #[test]
+    fn test_board_journey_0() {
+        let board: Board = TEST_INPUT_0.parse().unwrap();
+        let (start, goal) = (board.start(), board.goal());
+
+        let journey = board.journey(&[start, goal, start, goal]).unwrap();
+        assert_eq!(&[18, 23, 13], journey.legs());
+        assert_eq!(54, journey.total());
+        assert_eq!(Ok(18), board.part1());
+        assert_eq!(Ok(54), board.part2());
+    }
+
+    #[test]
+    fn test_board_journey_1() {
+        let board: Board = TEST_INPUT_1.parse().unwrap();
+
+        let journey = board.journey(&[board.start(), board.goal()]).unwrap();
+        assert_eq!(9, journey.total());
     }
 
     #[test]
-    fn test_board_solve_0() {
-        let mut board: Board<6, 4> = TEST_INPUT_0.parse().unwrap();
+    fn test_board_journey_2() {
+        let board: Board = TEST_INPUT_2.parse().unwrap();
 
-        let result = board.solve(Coordinate::MIN, Coordinate::MAX);
-        assert_eq!(18, result, "Hazzards in the board: {:?}", board.hazzards);
-        let result = board.solve(Coordinate::MAX, Coordinate::MIN);
-        assert_eq!(41, result, "Hazzards in the board: {:?}", board.hazzards);
-        let result = board.solve(Coordinate::MIN, Coordinate::MAX);
-        assert_eq!(54, result, "Hazzards in the board: {:?}", board.hazzards);
+        let journey = board.journey(&[board.start(), board.goal()]).unwrap();
+        assert_eq!(4, journey.total());
+        assert_eq!(0, board.journey(&[board.start()]).unwrap().total());
     }
 
     #[test]
-    fn test_board_solve_1() {
-        let mut board: Board<5, 3> = TEST_INPUT_1.parse().unwrap();
+    fn test_board_gaps() {
+        let board: Board = "##.#\n#..#\n#..#\n#.##".parse().unwrap();
+
+        assert_eq!(Coordinate::new(1, 0), board.start());
+        assert_eq!(Coordinate::new(0, 1), board.goal());
+        let journey = board.journey(&[board.start(), board.goal()]).unwrap();
+        assert_eq!(4, journey.total());
+    }
 
-        let result = board.solve(Coordinate::MIN, Coordinate::MAX);
-        assert_eq!(9, result, "Hazzards in the board: {:?}", board.hazzards);
+    #[test]
+    fn test_board_journey_waypoints() {
+        let board: Board = "#.##\n#..#\n#..#\n##.#".parse().unwrap();
+        let (start, goal) = (board.start(), board.goal());
+        let corner = Coordinate::new(1, 0);
+
+        let journey = board.journey(&[start, corner, goal]).unwrap();
+        assert_eq!(&[2, 2], journey.legs());
+        assert_eq!(4, journey.total());
+        let journey = board.journey(&[goal, corner, start]).unwrap();
+        assert_eq!(&[2, 2], journey.legs());
+
+        // A blizzard sweeps the middle row, so the expedition waits for it to pass the waypoint
+        // and can't stay there.
+        let board: Board = "#.##\n#..#\n#>.#\n#..#\n##.#".parse().unwrap();
+        let (start, goal) = (board.start(), board.goal());
+        let middle = Coordinate::new(0, 1);
+        let journey = board.journey(&[start, middle, goal]).unwrap();
+        assert_eq!(&[3, 3], journey.legs());
     }
 
     #[test]
-    fn test_board_solve_2() {
-        let mut board: Board<2, 2> = TEST_INPUT_2.parse().unwrap();
+    fn test_blizzards() {
+        let board: Board = TEST_INPUT_0.parse().unwrap();
+        let blizzards = &board.blizzards;
 
-        let result = board.solve(Coordinate::MIN, Coordinate::MAX);
-        assert_eq!(4, result, "Hazzards in the board: {:?}", board.hazzards);
+        assert_eq!(12, blizzards.period);
+        assert_eq!(blizzards.at(0), blizzards.at(12));
+        // Minute 1 of the example, with the row below the entrance at `.>3.<.`.
+        assert_eq!(
+            &[0b01_0110, 0b01_1001, 0b01_1011, 0b11_0011],
+            blizzards.at(1)
+        );
+    }
+
+    #[test]
+    fn test_board_journey_errors() {
+        let board: Board = "#.###\n#.>.#\n#.^.#\n###.#".parse().unwrap();
+        assert!(board.journey(&[Coordinate::new(3, 0)]).is_err());
+
+        // A blizzard that never leaves the only tile of the valley.
+        let board: Board = "#.#\n#>#\n#.#".parse().unwrap();
+        assert!(board.journey(&[board.start(), board.goal()]).is_err());
+    }
+
+    #[test]
+    fn test_board_from_str_errors() {
+        let column = |input: &str| {
+            let error = input.parse::<Board>().unwrap_err().locate(input);
+            error
+                .location()
+                .map(|location| (location.line, location.column))
+        };
+        let replace = |from: &str, to: &str| TEST_INPUT_0.replace(from, to);
+
+        assert_eq!(Some((3, 5)), column(&replace("#.<..<<#", "#.<.#<<#")));
+        assert_eq!(Some((3, 8)), column(&replace("#.<..<<#", "#.<..<<")));
+        assert_eq!(Some((4, 9)), column(&replace("#>v.><>#", "#>v.><>##")));
+        assert_eq!(Some((5, 4)), column(&replace("#<^v^^>#", "#<^x^^>#")));
+        assert_eq!(Some((1, 5)), column(&replace("#.######", "#.##.###")));
+        assert_eq!(Some((6, 1)), column(&replace("######.#", "########")));
+        assert_eq!(Some((1, 1)), column(&replace("#.######", ".#######")));
+        assert_eq!(Some((2, 1)), column("#.#\n#.#"));
     }
 }
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use std::io::{self, Result};
+use crate::{error::Error, solution::Solution};
 
 fn max3(x: usize, (a, b, c): (usize, usize, usize)) -> (usize, usize, usize) {
     if x > a {
@@ -14,29 +14,69 @@ fn max3(x: usize, (a, b, c): (usize, usize, usize)) -> (usize, usize, usize) {
     }
 }
 
-fn sum_segment<I: Iterator<Item = Result<String>>>(iter: &mut I) -> usize {
-    iter.map_while(|s| s.ok().and_then(|line| line.parse::<usize>().ok()))
-        .sum::<usize>()
-}
+/// Total calories carried by each elf.
+pub struct Inventory(Vec<usize>);
 
-fn solutionate<I: Iterator<Item = Result<String>>>(iter: I) -> usize {
-    let mut iter = iter.peekable();
-    let mut acc = (0, 0, 0);
+impl Solution for Inventory {
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    loop {
-        let x = sum_segment(iter.by_ref());
-        acc = max3(x, acc);
+    fn parse(input: &str) -> Result<Self, Error> {
+        let mut totals = Vec::new();
+        let mut total = 0;
 
-        if iter.peek().is_none() {
-            break;
+        for line in input.lines() {
+            if line.is_empty() {
+                totals.push(total);
+                total = 0;
+            } else {
+                total += line
+                    .parse::<usize>()
+                    .map_err(|e| Error::at(line, format!("invalid calories: {}", e)))?;
+            }
         }
+        totals.push(total);
+
+        Ok(Self(totals))
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        self.0
+            .iter()
+            .copied()
+            .max()
+            .ok_or_else(|| Error::new("no elves"))
     }
 
-    let (a, b, c) = acc;
-    a + b + c
+    fn part2(&self) -> Result<usize, Error> {
+        let (a, b, c) = self.0.iter().fold((0, 0, 0), |acc, &x| max3(x, acc));
+        Ok(a + b + c)
+    }
 }
 
-pub fn main() {
-    let result = solutionate(io::stdin().lines());
-    println!("{}", result);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn test_solution() {
+        let inventory = Inventory::parse(INPUT).unwrap();
+        assert_eq!(Ok(24000), inventory.part1());
+        assert_eq!(Ok(45000), inventory.part2());
+    }
 }
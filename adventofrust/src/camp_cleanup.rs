@@ -1,42 +1,60 @@
 #![allow(dead_code)]
-use std::io;
 
-pub fn main() -> Result<(), String> {
-    let iter = io::stdin().lines().map(|x| x.map_err(|e| e.to_string()));
-    let result = solutionate(iter)?;
-    println!("{}", result);
+use crate::{error::Error, solution::Solution};
 
-    Ok(())
-}
+type Assignment = (usize, usize);
+
+/// Section assignments of each pair of elves.
+pub struct Assignments(Vec<(Assignment, Assignment)>);
+
+impl Solution for Assignments {
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-fn solutionate(iter: impl Iterator<Item = Result<String, String>>) -> Result<usize, String> {
-    let mut count = 0;
-    for line in iter {
-        if check_line(&line?)? {
-            count += 1;
-        }
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(parse_line)
+            .collect::<Result<_, _>>()
+            .map(Self)
     }
-    Ok(count)
-}
 
-fn check_line(s: &str) -> Result<bool, String> {
-    fn parse_range(s: &str) -> Option<(usize, usize)> {
-        let (low, high) = s.split_once('-')?;
-        let low: usize = low.parse().ok()?;
-        let high: usize = high.parse().ok()?;
+    fn part1(&self) -> Result<usize, Error> {
+        Ok(self.0.iter().filter(|&&(a, b)| contains(a, b)).count())
+    }
 
-        Some((low, high))
+    fn part2(&self) -> Result<usize, Error> {
+        Ok(self.0.iter().filter(|&&(a, b)| overlaps(a, b)).count())
     }
+}
 
-    fn aux(s: &str) -> Option<bool> {
-        let (a, b) = s.split_once(',')?;
-        let (a_low, a_high) = parse_range(a)?;
-        let (b_low, b_high) = parse_range(b)?;
+fn parse_line(s: &str) -> Result<(Assignment, Assignment), Error> {
+    fn parse_range(s: &str) -> Result<Assignment, Error> {
+        let (low, high) = s
+            .split_once('-')
+            .ok_or_else(|| Error::at(s, "expected a range of sections like `2-4`"))?;
+        let section = |n: &str| {
+            n.parse::<usize>()
+                .map_err(|e| Error::at(n, format!("invalid section: {}", e)))
+        };
+        let (low, high) = (section(low)?, section(high)?);
 
-        Some(!(a_high < b_low || a_low > b_high))
+        Ok((low, high))
     }
 
-    aux(s).ok_or_else(|| format!("failed to parse line {}", s))
+    let (a, b) = s
+        .split_once(',')
+        .ok_or_else(|| Error::at(s, "expected two comma-separated ranges"))?;
+    Ok((parse_range(a)?, parse_range(b)?))
+}
+
+/// Whether either assignment fully contains the other.
+fn contains((a_low, a_high): Assignment, (b_low, b_high): Assignment) -> bool {
+    (a_low <= b_low && b_high <= a_high) || (b_low <= a_low && a_high <= b_high)
+}
+
+fn overlaps((a_low, a_high): Assignment, (b_low, b_high): Assignment) -> bool {
+    !(a_high < b_low || a_low > b_high)
 }
 
 #[cfg(test)]
@@ -50,6 +68,10 @@ mod tests {
 6-6,4-6
 2-6,4-8";
 
+    fn check_line(s: &str) -> Result<bool, Error> {
+        parse_line(s).map(|(a, b)| overlaps(a, b))
+    }
+
     #[test]
     fn test_check_line() {
         assert_eq!(Ok(true), check_line("2-8,3-7"));
@@ -60,6 +82,8 @@ mod tests {
 
     #[test]
     fn test_solution() {
-        assert_eq!(Ok(4), solutionate(INPUT.lines().map(|x| Ok(x.to_owned()))));
+        let assignments = Assignments::parse(INPUT).unwrap();
+        assert_eq!(Ok(2), assignments.part1());
+        assert_eq!(Ok(4), assignments.part2());
     }
 }
@@ -1,5 +1,7 @@
 #![allow(dead_code)]
-use std::{fmt::Display, io, num::ParseIntError, str::FromStr};
+use std::{fmt::Display, num::ParseIntError, str::FromStr};
+
+use crate::{error::Error, solution::Solution};
 
 #[derive(Debug, Clone, Copy)]
 enum Instruction {
@@ -8,27 +10,32 @@ enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut iter = s.split_whitespace();
-        match iter.next().ok_or("empty input")? {
-            "noop" => Ok(Self::Noop),
-            "addx" => Ok(Self::AddX(
-                iter.next()
-                    .ok_or_else(|| format!("incomplete instruction: {}", s))?
+        match iter.next() {
+            Some("noop") => Ok(Self::Noop),
+            Some("addx") => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| Error::at(&s[s.len()..], "missing value to add"))?;
+                value
                     .parse()
-                    .map_err(|e: ParseIntError| e.to_string())?,
-            )),
-            _ => Err(format!("invalid instruction: {}", s)),
+                    .map(Self::AddX)
+                    .map_err(|e: ParseIntError| Error::at(value, e.to_string()))
+            }
+            Some(instruction) => Err(Error::at(instruction, "expected noop or addx")),
+            None => Err(Error::at(s, "empty instruction")),
         }
     }
 }
 
-struct Tele {
+pub struct Tele {
     register: i32,
     cycle_id: i32,
     output: Vec<char>,
+    signal_strength: i32,
 }
 
 impl Tele {
@@ -37,6 +44,7 @@ impl Tele {
             register: 1,
             cycle_id: 0,
             output: Vec::with_capacity(40),
+            signal_strength: 0,
         }
     }
 
@@ -52,6 +60,11 @@ impl Tele {
     }
 
     fn draw(&mut self) {
+        let cycle = self.cycle_id + 1;
+        if cycle % 40 == 20 {
+            self.signal_strength += cycle * self.register;
+        }
+
         let ch = if (self.cycle_id % 40 - self.register).abs() < 2 {
             '#'
         } else {
@@ -74,17 +87,37 @@ impl Display for Tele {
     }
 }
 
-pub fn main() -> Result<(), String> {
-    let iter = io::stdin().lines();
-    let mut tele = Tele::new();
+pub struct Program(Vec<Instruction>);
+
+impl Program {
+    fn run(&self) -> Tele {
+        let mut tele = Tele::new();
+        for &instruction in &self.0 {
+            tele.execute(instruction);
+        }
+        tele
+    }
+}
+
+impl Solution for Program {
+    type Answer1 = i32;
+    type Answer2 = Tele;
 
-    for input in iter {
-        let instruction = input.map_err(|e| e.to_string())?.parse()?;
-        tele.execute(instruction);
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map(Self)
     }
-    println!("{}", tele);
 
-    Ok(())
+    fn part1(&self) -> Result<i32, Error> {
+        Ok(self.run().signal_strength)
+    }
+
+    fn part2(&self) -> Result<Tele, Error> {
+        Ok(self.run())
+    }
 }
 
 #[cfg(test)]
@@ -254,5 +287,6 @@ noop";
         }
         let result = tele.to_string();
         assert_eq!(OUTPUT, result, "\nexpect:\n{}\ngot:\n{}\n", OUTPUT, result);
+        assert_eq!(13140, tele.signal_strength);
     }
 }
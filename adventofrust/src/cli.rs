@@ -0,0 +1,557 @@
+//! Command-line front end for the puzzle registry of a calendar.
+
+use crate::{
+    client::Client,
+    fetch::{self, Fetched},
+    input::Source,
+    registry::{Calendar, Puzzle},
+    scaffold,
+    solution::Part,
+    submit::{self, History, Verdict},
+    timing,
+};
+
+const USAGE: &str = "usage:
+    list
+    run <puzzle|day|all> [--part 1|2] [--input PATH|-] [--trace]
+    fetch <puzzle|day|all>
+    submit <puzzle|day> <1|2> [ANSWER]
+    new <day> <module> <title>
+    time [<puzzle|day|all>] [--runs N] [--json]";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Target {
+    All,
+    Query(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    List,
+    Run {
+        target: Target,
+        part: Option<Part>,
+        input: Option<String>,
+        trace: bool,
+    },
+    Fetch {
+        target: Target,
+    },
+    Submit {
+        query: String,
+        part: Part,
+        answer: Option<String>,
+    },
+    New {
+        day: u8,
+        name: String,
+        title: String,
+    },
+    Time {
+        target: Target,
+        runs: usize,
+        json: bool,
+    },
+}
+
+impl Target {
+    fn from_arg(arg: &str) -> Self {
+        match arg {
+            "all" => Self::All,
+            query => Self::Query(query.to_owned()),
+        }
+    }
+
+    fn select(&self, calendar: &Calendar) -> Result<Vec<&'static Puzzle>, String> {
+        match self {
+            Self::All => Ok(calendar.puzzles.iter().collect()),
+            Self::Query(query) => {
+                let puzzles = calendar.select(query);
+                if puzzles.is_empty() {
+                    return Err(format!("no puzzle matches {}", query));
+                }
+                Ok(puzzles)
+            }
+        }
+    }
+}
+
+impl Command {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+
+        match args.next().as_deref() {
+            Some("list") => {
+                if let Some(arg) = args.next() {
+                    return Err(format!("unexpected argument: {}", arg));
+                }
+                Ok(Self::List)
+            }
+            Some("run") => {
+                let target = Target::from_arg(&args.next().ok_or("missing puzzle to run")?);
+                let mut part = None;
+                let mut input = None;
+                let mut trace = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--part" => {
+                            let value = args.next().ok_or("missing value for --part")?;
+                            part = Some(Part::try_from(value.as_str())?);
+                        }
+                        "--input" => {
+                            input = Some(args.next().ok_or("missing value for --input")?);
+                        }
+                        "--trace" => trace = true,
+                        _ => return Err(format!("unexpected argument: {}", arg)),
+                    }
+                }
+
+                if target == Target::All && input.is_some() {
+                    return Err("--input cannot be used when running all puzzles".to_owned());
+                }
+                if target == Target::All && trace {
+                    return Err("--trace cannot be used when running all puzzles".to_owned());
+                }
+
+                Ok(Self::Run {
+                    target,
+                    part,
+                    input,
+                    trace,
+                })
+            }
+            Some("fetch") => {
+                let target = Target::from_arg(&args.next().ok_or("missing puzzle to fetch")?);
+                if let Some(arg) = args.next() {
+                    return Err(format!("unexpected argument: {}", arg));
+                }
+                Ok(Self::Fetch { target })
+            }
+            Some("submit") => {
+                let query = args.next().ok_or("missing puzzle to submit")?;
+                let part = Part::try_from(args.next().ok_or("missing part to submit")?.as_str())?;
+                let answer = args.next();
+                if let Some(arg) = args.next() {
+                    return Err(format!("unexpected argument: {}", arg));
+                }
+                Ok(Self::Submit {
+                    query,
+                    part,
+                    answer,
+                })
+            }
+            Some("new") => {
+                let day = args.next().ok_or("missing day of the new puzzle")?;
+                let day = day.parse().map_err(|_| format!("invalid day: {}", day))?;
+                let name = args.next().ok_or("missing module of the new puzzle")?;
+                let title = args.collect::<Vec<_>>().join(" ");
+                if title.is_empty() {
+                    return Err("missing title of the new puzzle".to_owned());
+                }
+                Ok(Self::New { day, name, title })
+            }
+            Some("time") => {
+                let mut target = Target::All;
+                let mut runs = 1;
+                let mut json = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--runs" => {
+                            let value = args.next().ok_or("missing value for --runs")?;
+                            runs = value
+                                .parse()
+                                .ok()
+                                .filter(|&runs| runs > 0)
+                                .ok_or_else(|| format!("invalid number of runs: {}", value))?;
+                        }
+                        "--json" => json = true,
+                        _ if arg.starts_with("--") => {
+                            return Err(format!("unexpected argument: {}", arg))
+                        }
+                        query => target = Target::from_arg(query),
+                    }
+                }
+
+                Ok(Self::Time { target, runs, json })
+            }
+            Some(command) => Err(format!("unknown command: {}", command)),
+            None => Err("missing command".to_owned()),
+        }
+    }
+}
+
+fn list(calendar: &Calendar) {
+    for puzzle in calendar.puzzles {
+        let parts = Part::MEMBERS
+            .into_iter()
+            .map(|part| {
+                if puzzle.is_solved(part) {
+                    char::from_digit(usize::from(part) as u32, 10).unwrap_or('?')
+                } else {
+                    '-'
+                }
+            })
+            .collect::<String>();
+        println!(
+            "{:>2}  {:<32} {:<34} [{}]",
+            puzzle.day, puzzle.name, puzzle.title, parts
+        );
+    }
+}
+
+fn run_puzzle(
+    calendar: &Calendar,
+    puzzle: &Puzzle,
+    part: Option<Part>,
+    input: Option<&str>,
+    trace: bool,
+) -> Result<(), String> {
+    if trace && !puzzle.can_trace() {
+        return Err(format!("{} cannot be traced", puzzle.name));
+    }
+
+    let parts = match part {
+        Some(part) => {
+            if !puzzle.is_solved(part) {
+                return Err(format!(
+                    "{} has no solution for part {}",
+                    puzzle.name,
+                    usize::from(part)
+                ));
+            }
+            vec![part]
+        }
+        None => Part::MEMBERS
+            .into_iter()
+            .filter(|&part| puzzle.is_solved(part))
+            .collect(),
+    };
+
+    println!("Day {}: {}", puzzle.day, puzzle.title);
+    if parts.is_empty() {
+        println!("  no solution yet");
+        return Ok(());
+    }
+
+    let source = input
+        .map(Source::from_arg)
+        .unwrap_or_else(|| Source::Path(puzzle.input_path(&calendar.input_dir)));
+    let input = source.read().map_err(|e| e.to_string())?;
+
+    if trace {
+        for part in parts {
+            let trace = puzzle.trace(&input, part).map_err(|e| e.to_string())?;
+            println!("  part {}:\n{}", usize::from(part), trace);
+        }
+        return Ok(());
+    }
+
+    let solution = puzzle.parse(&input).map_err(|e| e.to_string())?;
+
+    for part in parts {
+        let answer = solution.solve(part).map_err(|e| e.to_string())?;
+        if answer.contains('\n') {
+            println!("  part {}:\n{}", usize::from(part), answer);
+        } else {
+            println!("  part {}: {}", usize::from(part), answer);
+        }
+    }
+
+    Ok(())
+}
+
+fn run(
+    calendar: &Calendar,
+    target: Target,
+    part: Option<Part>,
+    input: Option<String>,
+    trace: bool,
+) -> Result<(), String> {
+    match target {
+        Target::All => {
+            let mut failures = 0;
+            for puzzle in calendar.puzzles {
+                if let Err(e) = run_puzzle(calendar, puzzle, part, None, false) {
+                    println!("  error: {}", e);
+                    failures += 1;
+                }
+            }
+
+            if failures == 0 {
+                Ok(())
+            } else {
+                Err(format!("{} puzzle(s) failed", failures))
+            }
+        }
+        Target::Query(_) => {
+            for puzzle in target.select(calendar)? {
+                run_puzzle(calendar, puzzle, part, input.as_deref(), trace)?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Downloads the inputs of the selected puzzles that aren't on disk yet. The session is only
+/// needed when something has to be downloaded.
+fn fetch(calendar: &Calendar, target: Target) -> Result<(), String> {
+    let mut inputs = Vec::new();
+    for puzzle in target.select(calendar)? {
+        let input = (puzzle.day, puzzle.input_path(&calendar.input_dir));
+        if !inputs.contains(&input) {
+            inputs.push(input);
+        }
+    }
+
+    let mut client = None;
+    for (day, path) in inputs {
+        if !path.exists() && client.is_none() {
+            client = Some(Client::from_env().map_err(|e| e.to_string())?);
+        }
+        let fetched = match &client {
+            Some(client) => {
+                fetch::fetch_input(client, calendar.year, day, &path).map_err(|e| e.to_string())?
+            }
+            None => Fetched::Cached,
+        };
+
+        let status = match fetched {
+            Fetched::Cached => "cached",
+            Fetched::Downloaded => "downloaded",
+        };
+        println!("Day {}: {} ({})", day, path.display(), status);
+    }
+
+    Ok(())
+}
+
+/// Submits `answer`, or the answer computed by the first puzzle matching `query` on its input
+/// when there is none. Every attempt is recorded in `submissions.log` next to the inputs.
+fn submit(
+    calendar: &Calendar,
+    query: &str,
+    part: Part,
+    answer: Option<String>,
+) -> Result<(), String> {
+    let puzzle = Target::Query(query.to_owned()).select(calendar)?[0];
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            if !puzzle.is_solved(part) {
+                return Err(format!(
+                    "{} has no solution for part {}",
+                    puzzle.name,
+                    usize::from(part)
+                ));
+            }
+            let input = Source::Path(puzzle.input_path(&calendar.input_dir))
+                .read()
+                .map_err(|e| e.to_string())?;
+            let solution = puzzle.parse(&input).map_err(|e| e.to_string())?;
+            solution.solve(part).map_err(|e| e.to_string())?
+        }
+    };
+
+    let mut history = History::load(&calendar.input_dir.root().join("submissions.log"))
+        .map_err(|e| e.to_string())?;
+    let client = Client::from_env().map_err(|e| e.to_string())?;
+    let verdict = submit::submit(
+        &client,
+        &mut history,
+        calendar.year,
+        puzzle.day,
+        part,
+        &answer,
+    )
+    .map_err(|e| e.to_string())?;
+
+    let message = match verdict {
+        Verdict::Correct => "correct".to_owned(),
+        Verdict::TooHigh => "wrong, too high".to_owned(),
+        Verdict::TooLow => "wrong, too low".to_owned(),
+        Verdict::Wrong => "wrong".to_owned(),
+        Verdict::Wait(delay) => format!("not checked, wait {}s", delay.as_secs()),
+        Verdict::WrongLevel => "not checked, the part is solved or still locked".to_owned(),
+    };
+    println!(
+        "Day {} part {}: {} is {}",
+        puzzle.day,
+        usize::from(part),
+        answer,
+        message
+    );
+
+    match verdict {
+        Verdict::Correct => Ok(()),
+        _ => Err(format!("{} was not accepted", answer)),
+    }
+}
+
+/// Times every selected puzzle on its input, and prints the timings slowest first, or as JSON.
+fn time(calendar: &Calendar, target: Target, runs: usize, json: bool) -> Result<(), String> {
+    let mut timings = Vec::new();
+    let mut failures = Vec::new();
+
+    for puzzle in target.select(calendar)? {
+        let result = Source::Path(puzzle.input_path(&calendar.input_dir))
+            .read()
+            .and_then(|input| timing::time(puzzle, &input, runs));
+        match result {
+            Ok(timing) => timings.push(timing),
+            Err(e) => failures.push((puzzle, e)),
+        }
+    }
+
+    if json {
+        println!("{}", timing::json(&timings, &failures));
+    } else {
+        print!("{}", timing::table(&timings));
+        for (puzzle, e) in &failures {
+            println!("  error: {}: {}", puzzle.name, e);
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} puzzle(s) failed", failures.len()))
+    }
+}
+
+/// Runs the command described by `args` on the puzzles of `calendar`; `args` should not include
+/// the program name.
+pub fn main(calendar: &Calendar, args: impl IntoIterator<Item = String>) -> Result<(), String> {
+    let command = Command::parse(args).map_err(|e| format!("{}\n{}", e, USAGE))?;
+
+    match command {
+        Command::List => {
+            list(calendar);
+            Ok(())
+        }
+        Command::Run {
+            target,
+            part,
+            input,
+            trace,
+        } => run(calendar, target, part, input, trace),
+        Command::Fetch { target } => fetch(calendar, target),
+        Command::Submit {
+            query,
+            part,
+            answer,
+        } => submit(calendar, &query, part, answer),
+        Command::Time { target, runs, json } => time(calendar, target, runs, json),
+        Command::New { day, name, title } => {
+            for path in
+                scaffold::scaffold(calendar, day, &name, &title).map_err(|e| e.to_string())?
+            {
+                println!("{}", path.display());
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Command, String> {
+        Command::parse(s.split_whitespace().map(str::to_owned))
+    }
+
+    #[test]
+    fn test_command_parse() {
+        assert_eq!(Ok(Command::List), parse("list"));
+        assert_eq!(
+            Ok(Command::Run {
+                target: Target::Query("24".to_owned()),
+                part: Some(Part::Two),
+                input: Some("foo.txt".to_owned()),
+                trace: false,
+            }),
+            parse("run 24 --part 2 --input foo.txt")
+        );
+        assert_eq!(
+            Ok(Command::Run {
+                target: Target::Query("22".to_owned()),
+                part: None,
+                input: None,
+                trace: true,
+            }),
+            parse("run 22 --trace")
+        );
+        assert_eq!(
+            Ok(Command::Run {
+                target: Target::All,
+                part: None,
+                input: None,
+                trace: false,
+            }),
+            parse("run all")
+        );
+        assert_eq!(
+            Ok(Command::Fetch {
+                target: Target::Query("monkey_map".to_owned()),
+            }),
+            parse("fetch monkey_map")
+        );
+        assert_eq!(
+            Ok(Command::Submit {
+                query: "1".to_owned(),
+                part: Part::One,
+                answer: None,
+            }),
+            parse("submit 1 1")
+        );
+        assert_eq!(
+            Ok(Command::New {
+                day: 11,
+                name: "day11".to_owned(),
+                title: "Cosmic Expansion".to_owned(),
+            }),
+            parse("new 11 day11 Cosmic Expansion")
+        );
+        assert_eq!(
+            Ok(Command::Time {
+                target: Target::All,
+                runs: 1,
+                json: false,
+            }),
+            parse("time")
+        );
+        assert_eq!(
+            Ok(Command::Time {
+                target: Target::Query("5".to_owned()),
+                runs: 10,
+                json: true,
+            }),
+            parse("time 5 --runs 10 --json")
+        );
+    }
+
+    #[test]
+    fn test_command_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("walk").is_err());
+        assert!(parse("run").is_err());
+        assert!(parse("run 1 --part 3").is_err());
+        assert!(parse("run 1 --part").is_err());
+        assert!(parse("run all --input foo.txt").is_err());
+        assert!(parse("run all --trace").is_err());
+        assert!(parse("list everything").is_err());
+        assert!(parse("fetch").is_err());
+        assert!(parse("fetch 1 2").is_err());
+        assert!(parse("submit 1").is_err());
+        assert!(parse("submit 1 3 42").is_err());
+        assert!(parse("submit 1 1 42 43").is_err());
+        assert!(parse("new 11 day11").is_err());
+        assert!(parse("new eleven day11 Cosmic Expansion").is_err());
+        assert!(parse("time --runs 0").is_err());
+        assert!(parse("time --fast").is_err());
+    }
+}
@@ -0,0 +1,306 @@
+//! Authenticated access to the Advent of Code website. Requests go through the [`Http`] trait,
+//! so tests can talk to a local stand-in server instead of adventofcode.com.
+
+use std::{
+    cell::Cell,
+    env, thread,
+    time::{Duration, Instant},
+};
+
+use crate::error::Error;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the value of the `session` cookie of a logged-in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Minimum delay between two requests of a client, so that fetching a whole year doesn't hammer
+/// the site.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP layer of a [`Client`]. Error statuses are responses, not errors; errors are
+/// reserved for requests that got no response at all.
+pub trait Http {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, Error>;
+
+    fn post_form(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> Result<Response, Error>;
+}
+
+/// [`Http`] backed by `ureq`.
+pub struct Ureq(ureq::Agent);
+
+impl Default for Ureq {
+    fn default() -> Self {
+        Self(
+            ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        )
+    }
+}
+
+impl Ureq {
+    fn request(&self, method: &str, url: &str, headers: &[(&str, &str)]) -> ureq::Request {
+        headers
+            .iter()
+            .fold(self.0.request(method, url), |request, (name, value)| {
+                request.set(name, value)
+            })
+    }
+}
+
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, Error> {
+    let response = match result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => return Err(Error::new(format!("request failed: {}", e))),
+    };
+
+    let status = response.status();
+    let body = response
+        .into_string()
+        .map_err(|e| Error::new(format!("failed to read response: {}", e)))?;
+
+    Ok(Response { status, body })
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, Error> {
+        into_response(self.request("GET", url, headers).call())
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> Result<Response, Error> {
+        into_response(self.request("POST", url, headers).send_form(form))
+    }
+}
+
+/// A logged-in session on the website, which waits between requests to respect its rate limits.
+pub struct Client<H = Ureq> {
+    http: H,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl Client {
+    /// A client for adventofcode.com, logged in with the session in `AOC_SESSION`.
+    pub fn from_env() -> Result<Self, Error> {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "{} must hold the session cookie of adventofcode.com",
+                    SESSION_VAR
+                ))
+            })?;
+
+        Ok(Self::new(Ureq::default(), BASE_URL, session.trim()))
+    }
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, base_url: &str, session: &str) -> Self {
+        Self {
+            http,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            min_interval: MIN_INTERVAL,
+            last_request: Cell::new(None),
+        }
+    }
+
+    pub fn with_min_interval(self, min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            ..self
+        }
+    }
+
+    /// Sends a GET request for `path`, which is relative to the base URL.
+    pub fn get(&self, path: &str) -> Result<Response, Error> {
+        self.throttle();
+        let cookie = self.cookie();
+        let response = self.http.get(&self.url(path), &[("Cookie", &cookie)])?;
+        self.check_rate_limit(response)
+    }
+
+    /// Sends `form` in a POST request to `path`, which is relative to the base URL.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, Error> {
+        self.throttle();
+        let cookie = self.cookie();
+        let response = self
+            .http
+            .post_form(&self.url(path), &[("Cookie", &cookie)], form)?;
+        self.check_rate_limit(response)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Sleeps until `min_interval` has passed since the previous request.
+    fn throttle(&self) {
+        if let Some(last) = self.last_request.get() {
+            if let Some(wait) = self.min_interval.checked_sub(last.elapsed()) {
+                thread::sleep(wait);
+            }
+        }
+        self.last_request.set(Some(Instant::now()));
+    }
+
+    fn check_rate_limit(&self, response: Response) -> Result<Response, Error> {
+        if response.status == 429 {
+            return Err(Error::new(format!(
+                "rate limited by {}, try again later",
+                self.base_url
+            )));
+        }
+        Ok(response)
+    }
+}
+
+/// A local HTTP server answering canned responses in order, one per connection.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    pub struct StandIn {
+        pub url: String,
+        requests: Receiver<String>,
+    }
+
+    impl StandIn {
+        /// The requests received so far, each as its request line, headers and body.
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.try_iter().collect()
+        }
+    }
+
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> StandIn {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, requests) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap_or(0);
+                        }
+                    }
+                    request.push_str(&line);
+                }
+                let mut request_body = vec![0; content_length];
+                let _ = reader.read_exact(&mut request_body);
+                request.push_str(&String::from_utf8_lossy(&request_body));
+
+                let _ = sender.send(request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+
+        StandIn { url, requests }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stand_in::serve, *};
+
+    #[test]
+    fn test_get_sends_session() {
+        let server = serve(vec![(200, "hello")]);
+        let client = Client::new(Ureq::default(), &server.url, "abc123");
+
+        let response = client.get("/2022/day/1/input").unwrap();
+        assert_eq!(
+            Response {
+                status: 200,
+                body: "hello".to_owned()
+            },
+            response
+        );
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_error_status_is_a_response() {
+        let server = serve(vec![(404, "not yet")]);
+        let client = Client::new(Ureq::default(), &server.url, "abc123");
+
+        assert_eq!(404, client.get("/2022/day/26/input").unwrap().status);
+    }
+
+    #[test]
+    fn test_rate_limited() {
+        let server = serve(vec![(429, "slow down")]);
+        let client = Client::new(Ureq::default(), &server.url, "abc123");
+
+        let e = client.get("/2022/day/1/input").unwrap_err();
+        assert!(e.message().starts_with("rate limited"));
+    }
+
+    #[test]
+    fn test_throttle() {
+        let server = serve(vec![(200, "1"), (200, "2")]);
+        let interval = Duration::from_millis(200);
+        let client =
+            Client::new(Ureq::default(), &server.url, "abc123").with_min_interval(interval);
+
+        let start = Instant::now();
+        client.get("/2022/day/1/input").unwrap();
+        client.get("/2022/day/2/input").unwrap();
+        assert!(start.elapsed() >= interval);
+    }
+}
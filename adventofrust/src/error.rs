@@ -0,0 +1,244 @@
+//! Error type shared by the puzzle solutions.
+//!
+//! Parsers point errors at the offending part of the input with [`Error::at`], which remembers
+//! where that substring lives in memory. Once the error reaches code that holds the whole
+//! input, [`Error::locate`] turns that into a line and column, so the error can be shown with
+//! the offending line and a caret under the failing characters.
+
+use std::{
+    fmt::{Display, Formatter},
+    ops::Range,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// Number of characters of the offending snippet on that line, at least 1.
+    pub len: usize,
+    pub source_line: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+    puzzle: Option<&'static str>,
+    /// Address range of the offending snippet, until it is resolved into `location`.
+    span: Option<Range<usize>>,
+    location: Option<Location>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            puzzle: None,
+            span: None,
+            location: None,
+        }
+    }
+
+    /// An error caused by `snippet`, which must be a substring of the input for the error to be
+    /// located later. An empty snippet points at the position right where it starts.
+    pub fn at(snippet: &str, message: impl Into<String>) -> Self {
+        let start = snippet.as_ptr() as usize;
+        Self {
+            span: Some(start..start + snippet.len()),
+            ..Self::new(message)
+        }
+    }
+
+    pub fn in_puzzle(self, puzzle: &'static str) -> Self {
+        Self {
+            puzzle: Some(puzzle),
+            ..self
+        }
+    }
+
+    /// Resolves the snippet the error was created with into a location in `input`. Errors
+    /// without a snippet, or whose snippet isn't part of `input`, are left as they are.
+    pub fn locate(mut self, input: &str) -> Self {
+        let Some(span) = self.span.clone() else {
+            return self;
+        };
+
+        let base = input.as_ptr() as usize;
+        if span.start < base || base + input.len() < span.end {
+            return self;
+        }
+
+        let start = span.start - base;
+        let end = span.end - base;
+
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        let source_line = input[line_start..line_end].trim_end_matches('\r');
+
+        let column = input[line_start..start].chars().count() + 1;
+        let len = input[start..end.min(line_end)].chars().count().max(1);
+
+        self.span = None;
+        self.location = Some(Location {
+            line: input[..start].matches('\n').count() + 1,
+            column,
+            len,
+            source_line: source_line.to_owned(),
+        });
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn puzzle(&self) -> Option<&'static str> {
+        self.puzzle
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(puzzle) = self.puzzle {
+            write!(f, "{}: ", puzzle)?;
+        }
+        f.write_str(&self.message)?;
+
+        let Some(location) = &self.location else {
+            return Ok(());
+        };
+
+        // Keep tabs in the padding so the caret lines up with the source line.
+        let padding: String = location
+            .source_line
+            .chars()
+            .take(location.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let gutter = " ".repeat(location.line.to_string().len());
+
+        write!(
+            f,
+            "\n{gutter}--> line {}, column {}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+            location.line,
+            location.column,
+            location.line,
+            location.source_line,
+            padding,
+            "^".repeat(location.len),
+            gutter = gutter,
+        )
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Fails on the first non-ASCII character of `s`, for parsers that index their input by byte.
+pub fn ensure_ascii(s: &str) -> Result<(), Error> {
+    match s.char_indices().find(|(_, c)| !c.is_ascii()) {
+        Some((i, c)) => Err(Error::at(
+            &s[i..i + c.len_utf8()],
+            "non-ASCII characters are not supported",
+        )),
+        None => Ok(()),
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#.######
+#>>.<^<#
+#.<..<?#
+######.#";
+
+    #[test]
+    fn test_locate() {
+        let line = INPUT.lines().nth(2).unwrap();
+        let error = Error::at(&line[6..7], "invalid tile").locate(INPUT);
+
+        assert_eq!(
+            Some(&Location {
+                line: 3,
+                column: 7,
+                len: 1,
+                source_line: "#.<..<?#".to_owned(),
+            }),
+            error.location()
+        );
+    }
+
+    #[test]
+    fn test_locate_foreign_snippet() {
+        let other = INPUT.to_owned();
+        let error = Error::at(&other[3..5], "invalid tile").locate(INPUT);
+        assert_eq!(None, error.location());
+
+        let error = Error::new("no snippet").locate(INPUT);
+        assert_eq!(None, error.location());
+    }
+
+    #[test]
+    fn test_locate_empty_snippet() {
+        let error = Error::at(&INPUT[INPUT.len()..], "unexpected end of input").locate(INPUT);
+        assert_eq!(
+            Some(&Location {
+                line: 4,
+                column: 9,
+                len: 1,
+                source_line: "######.#".to_owned(),
+            }),
+            error.location()
+        );
+    }
+
+    #[test]
+    fn test_ensure_ascii() {
+        let input = "ab\ncdé";
+        assert_eq!(Ok(()), ensure_ascii(INPUT));
+        assert_eq!(
+            Some(3),
+            ensure_ascii(input)
+                .unwrap_err()
+                .locate(input)
+                .location()
+                .map(|location| location.column)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let error = Error::at(&line[1..3], "too windy")
+            .locate(INPUT)
+            .in_puzzle("blizzard_basin");
+
+        assert_eq!(
+            "blizzard_basin: too windy
+ --> line 2, column 2
+  |
+2 | #>>.<^<#
+  |  ^^",
+            error.to_string()
+        );
+        assert_eq!("plain", Error::new("plain").to_string());
+    }
+}
@@ -0,0 +1,116 @@
+//! Download of puzzle inputs into their conventional location. Inputs never change once a puzzle
+//! is unlocked, so an input already on disk is never downloaded again.
+
+use std::{fs, path::Path};
+
+use crate::{
+    client::{Client, Http},
+    error::Error,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input of `day` of `year` into `path`, unless there is already a file there.
+pub fn fetch_input<H: Http>(
+    client: &Client<H>,
+    year: u16,
+    day: u8,
+    path: &Path,
+) -> Result<Fetched, Error> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let response = client.get(&format!("/{}/day/{}/input", year, day))?;
+    match response.status {
+        200 if !response.body.is_empty() => {}
+        200 => {
+            return Err(Error::new(format!(
+                "input of {} day {} is empty",
+                year, day
+            )))
+        }
+        400 => return Err(Error::new("session was rejected, it may have expired")),
+        404 => {
+            return Err(Error::new(format!(
+                "input of {} day {} is not available yet",
+                year, day
+            )))
+        }
+        status => {
+            return Err(Error::new(format!(
+                "unexpected status {} for the input of {} day {}",
+                status, year, day
+            )))
+        }
+    }
+
+    // Written next to its destination first, so an interrupted download doesn't leave a
+    // truncated input that would then be taken for a cached one.
+    let partial = path.with_extension("part");
+    let write_err =
+        |e: std::io::Error| Error::new(format!("failed to write {}: {}", path.display(), e));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(write_err)?;
+    }
+    fs::write(&partial, &response.body).map_err(write_err)?;
+    fs::rename(&partial, path).map_err(write_err)?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process, time::Duration};
+
+    use super::*;
+    use crate::client::{stand_in::serve, Ureq};
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = env::temp_dir().join(format!("adventofrust-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let server = serve(vec![(200, "1000\n2000\n")]);
+        let client =
+            Client::new(Ureq::default(), &server.url, "abc123").with_min_interval(Duration::ZERO);
+        let dir = temp_dir("fetch");
+        let path = dir.join("day 1/input.txt");
+
+        assert_eq!(
+            Ok(Fetched::Downloaded),
+            fetch_input(&client, 2023, 1, &path)
+        );
+        assert_eq!("1000\n2000\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(Ok(Fetched::Cached), fetch_input(&client, 2023, 1, &path));
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2023/day/1/input "));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_errors() {
+        let server = serve(vec![(404, "Not found"), (400, "Please log in")]);
+        let client =
+            Client::new(Ureq::default(), &server.url, "abc123").with_min_interval(Duration::ZERO);
+        let dir = temp_dir("fetch-errors");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+
+        assert!(fetch_input(&client, 2023, 25, &path).is_err());
+        assert!(fetch_input(&client, 2023, 1, &path).is_err());
+        assert!(!path.exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
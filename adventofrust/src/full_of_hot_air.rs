@@ -2,10 +2,15 @@
 //! https://adventofcode.com/2022/day/25
 
 #![allow(dead_code)]
-use std::{fmt::Display, fs, iter::repeat, ops::Add, str::FromStr, u8};
+use std::{fmt::Display, iter::repeat, ops::Add, str::FromStr};
+
+use crate::{
+    error::Error,
+    solution::{unsolved, Solution, Unsolved},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum SnafuDigitParseError {
+pub enum SnafuDigitParseError {
     InvalidChar,
     EmptyInput,
     NegativeValue,
@@ -27,8 +32,8 @@ enum SnafuCarry {
     Zero,
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct SnafuNumber(Vec<SnafuDigit>);
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnafuNumber(Vec<SnafuDigit>);
 
 impl Default for SnafuDigit {
     #[inline]
@@ -295,28 +300,48 @@ fn snafu_to_i64(input: &str) -> Option<i64> {
         })
 }
 
-const INPUT_FILENAME: &str = "input/full_of_hot_air.txt";
-
-pub fn main() -> Result<(), String> {
-    let input = fs::read_to_string(INPUT_FILENAME).map_err(|e| e.to_string())?;
-    let (result, result_i64) = input
-        .lines()
-        .try_fold((SnafuNumber::default(), 0i64), |(acc, acc_i64), line| {
-            let num: SnafuNumber = line.parse().ok()?;
-            let num_i64 = snafu_to_i64(line)?;
-            print!("{} | {} | {}; ", line, &num, num_i64);
-            let sum = num + acc;
-            let parsed_sum_i64 = snafu_to_i64(String::from(&sum).as_str())?;
-            let sum_i64 = num_i64 + acc_i64;
-            println!("sum: {} | {} | {}", sum, parsed_sum_i64, sum_i64);
-            assert_eq!(parsed_sum_i64, sum_i64);
-            Some((sum, sum_i64))
-        })
-        .ok_or("Invalid SNAFU number.")?;
+/// Fuel requirements of the hot air balloons.
+pub struct Requirements(Vec<SnafuNumber>);
+
+impl Solution for Requirements {
+    type Answer1 = SnafuNumber;
+    /// Day 25 has no second puzzle.
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(|line| {
+                line.parse().map_err(|e| match e {
+                    SnafuDigitParseError::InvalidChar => {
+                        let i = line
+                            .bytes()
+                            .position(|b| SnafuDigit::try_from(b).is_err())
+                            .unwrap_or_default();
+                        let len = line[i..].chars().next().map_or(0, char::len_utf8);
+                        Error::at(&line[i..i + len], "expected 2, 1, 0, - or =")
+                    }
+                    SnafuDigitParseError::EmptyInput => Error::at(line, "empty SNAFU number"),
+                    SnafuDigitParseError::NegativeValue => {
+                        Error::at(&line[..1], "SNAFU numbers can't be negative")
+                    }
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
 
-    println!("{}, {}", result, result_i64);
+    fn part1(&self) -> Result<SnafuNumber, Error> {
+        Ok(self
+            .0
+            .iter()
+            .cloned()
+            .fold(SnafuNumber::default(), |acc, num| num + acc))
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<Unsolved, Error> {
+        unsolved()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,117 @@
+//! Loading of puzzle inputs from stdin, an explicit path, or the conventional location of a
+//! puzzle's input. Conventional locations are absolute, so puzzles can be run from any working
+//! directory.
+
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Source {
+    /// The source named by a command-line argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::Path(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, Error> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| Error::new(format!("failed to read stdin: {}", e)))?;
+                Ok(input)
+            }
+            Self::Path(path) => fs::read_to_string(path)
+                .map_err(|e| Error::new(format!("failed to read {}: {}", path.display(), e))),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdin => f.write_str("stdin"),
+            Self::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Where a crate keeps its puzzle inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputDir {
+    /// Absolute path of the directory, usually built from `env!("CARGO_MANIFEST_DIR")`.
+    pub root: &'static str,
+    /// Path of an input relative to `root`, in which `{day}` is replaced by the day number and
+    /// `{input}` by the name of the input.
+    pub pattern: &'static str,
+}
+
+impl InputDir {
+    pub fn root(&self) -> &Path {
+        Path::new(self.root)
+    }
+
+    /// Conventional location of the input named `input` of `day`.
+    pub fn path(&self, day: u8, input: &str) -> PathBuf {
+        let relative = self
+            .pattern
+            .replace("{day}", &day.to_string())
+            .replace("{input}", input);
+        self.root().join(relative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::Stdin, Source::from_arg("-"));
+        assert_eq!(
+            Source::Path(PathBuf::from("input/foo.txt")),
+            Source::from_arg("input/foo.txt")
+        );
+    }
+
+    #[test]
+    fn test_input_dir_path() {
+        let flat = InputDir {
+            root: "/aoc/input",
+            pattern: "{input}.txt",
+        };
+        assert_eq!(
+            PathBuf::from("/aoc/input/rope_bridge.txt"),
+            flat.path(9, "rope_bridge")
+        );
+
+        let by_day = InputDir {
+            root: "/aoc/inputs",
+            pattern: "day {day}/input.txt",
+        };
+        assert_eq!(
+            PathBuf::from("/aoc/inputs/day 9/input.txt"),
+            by_day.path(9, "day9")
+        );
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = Source::Path(PathBuf::from("/nonexistent/input.txt"));
+        assert!(source.read().is_err());
+    }
+}
@@ -1,3 +1,28 @@
+pub mod answers;
 pub mod blizzard_basin;
+pub mod calorie_counting;
+pub mod camp_cleanup;
+pub mod cathode_ray_tube;
+pub mod cli;
+pub mod client;
+pub mod error;
+pub mod fetch;
 pub mod full_of_hot_air;
+pub mod input;
+pub mod monkey_in_the_middle;
+pub mod monkey_map;
+pub mod no_space_left_on_device;
+pub mod no_space_left_on_device_arena;
+pub mod registry;
+pub mod rock_paper_scissor;
+pub mod rope_bridge;
+pub mod rucksack_reorganization;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod supply_stacks;
+pub mod timing;
+pub mod treetop_tree_house;
+pub mod tuning_trouble;
+pub mod unstable_difusion;
 pub mod utils;
@@ -1,22 +1,13 @@
-mod blizzard_basin;
-mod calorie_counting;
-mod camp_cleanup;
-mod cathode_ray_tube;
-mod full_of_hot_air;
-mod monkey_in_the_middle;
-mod monkey_map;
-mod no_space_left_on_device;
-mod no_space_left_on_device_arena;
-mod rock_paper_scissor;
-mod rope_bridge;
-mod rucksack_reorganization;
-mod supply_stacks;
-mod treetop_tree_house;
-mod tuning_trouble;
-mod unstable_difusion;
+use std::process::ExitCode;
 
-fn main() -> Result<(), String> {
-    cathode_ray_tube::main()?;
+use adventofrust::registry::CALENDAR;
 
-    Ok(())
+fn main() -> ExitCode {
+    match adventofrust::cli::main(&CALENDAR, std::env::args().skip(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -1,10 +1,15 @@
+//! Day 11: Monkey in the Middle
+//! https://adventofcode.com/2022/day/11
+
 #![allow(dead_code)]
 
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::{ops::IndexMut, str::FromStr};
+use std::str::FromStr;
+
+use crate::{error::Error, solution::Solution, utils::paragraphs::paragraphs};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Operation {
     MulSelf,
     Mul(usize),
@@ -14,22 +19,18 @@ enum Operation {
 impl Operation {
     fn new(s: &str) -> Option<Self> {
         lazy_static! {
-            static ref RE: Regex = Regex::new(r"Operation: new = old [+*] \w+").unwrap();
+            static ref RE: Regex = Regex::new(r"^new = old ([+*]) (old|\d+)$").unwrap();
         }
 
         let captures = RE.captures(s)?;
         let op_type = captures.get(1)?.as_str();
         let variable = captures.get(2)?.as_str();
 
-        let variable = if variable == "old" {
-            return Some(Self::MulSelf);
-        } else {
-            variable.parse::<usize>().ok()?
-        };
-
-        match op_type {
-            "*" => Some(Self::Mul(variable)),
-            "+" => Some(Self::Add(variable)),
+        match (op_type, variable) {
+            ("*", "old") => Some(Self::MulSelf),
+            ("+", "old") => Some(Self::Mul(2)),
+            ("*", n) => n.parse().ok().map(Self::Mul),
+            ("+", n) => n.parse().ok().map(Self::Add),
             _ => None,
         }
     }
@@ -38,20 +39,20 @@ impl Operation {
         match self {
             Operation::MulSelf => value * value,
             Operation::Mul(multiplier) => value * multiplier,
-            Operation::Add(adder) => value * adder,
+            Operation::Add(adder) => value + adder,
         }
     }
 }
 
 impl FromStr for Operation {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::new(s).ok_or_else(|| format!("invalid operation: {}", s))
+        Self::new(s).ok_or_else(|| Error::at(s, "expected `new = old + N` or `new = old * N`"))
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct Monkey {
     items: Vec<usize>,
     op: Operation,
@@ -60,26 +61,284 @@ struct Monkey {
     indivisible_next: usize,
 }
 
-struct Game {
+/// Parses a number of the notes, pointing at it if it isn't one.
+fn parse_number(s: &str, what: &str) -> Result<usize, Error> {
+    s.parse()
+        .map_err(|e| Error::at(s, format!("invalid {}: {}", what, e)))
+}
+
+impl FromStr for Monkey {
+    type Err = Error;
+
+    /// Parses the notes on one monkey, without its `Monkey N:` header.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim);
+        let mut field = |prefix: &str| {
+            let line = lines
+                .next()
+                .ok_or_else(|| Error::at(&s[s.len()..], format!("expected `{}`", prefix)))?;
+            line.strip_prefix(prefix)
+                .ok_or_else(|| Error::at(line, format!("expected `{}`", prefix)))
+        };
+
+        let items = field("Starting items:")?
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| parse_number(item, "worry level"))
+            .collect::<Result<_, _>>()?;
+        let op = field("Operation: ")?.parse()?;
+        let dividend = parse_number(field("Test: divisible by ")?, "divisor")?;
+        if dividend == 0 {
+            return Err(Error::new("monkeys can't test divisibility by 0"));
+        }
+        let divisible_next = parse_number(field("If true: throw to monkey ")?, "monkey")?;
+        let indivisible_next = parse_number(field("If false: throw to monkey ")?, "monkey")?;
+
+        if let Some(line) = lines.find(|line| !line.is_empty()) {
+            return Err(Error::at(
+                line,
+                "unexpected line after the notes on a monkey",
+            ));
+        }
+
+        Ok(Self {
+            items,
+            op,
+            dividend,
+            divisible_next,
+            indivisible_next,
+        })
+    }
+}
+
+/// How worry levels are kept in check after each inspection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relief {
+    /// Worry levels are divided by three, as long as items come out undamaged.
+    DivideByThree,
+    /// Worry levels are reduced modulo a multiple of every divisor the monkeys test, which
+    /// keeps them bounded without changing where any item is thrown.
+    Modulo(usize),
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub struct Game {
     monkeys: Vec<Monkey>,
 }
 
 impl Game {
-    fn tick(&mut self) {
-        for i in 0..self.monkeys.len() {
-            let monkey_ref = self.monkeys.index_mut(i);
-            let monkey = monkey_ref.clone();
-            monkey_ref.items.clear();
-
-            for &item in &monkey.items {
-                let new_value = monkey.op.exec(item) / 3;
-                let next_index = if new_value % monkey.dividend == 0 {
-                    monkey.divisible_next
+    /// Plays one round, adding the items inspected by each monkey to `inspections`.
+    fn tick(monkeys: &mut [Monkey], relief: Relief, inspections: &mut [usize]) {
+        for i in 0..monkeys.len() {
+            let items = std::mem::take(&mut monkeys[i].items);
+            let monkey = &monkeys[i];
+            let (op, dividend) = (monkey.op, monkey.dividend);
+            let (divisible_next, indivisible_next) =
+                (monkey.divisible_next, monkey.indivisible_next);
+            inspections[i] += items.len();
+
+            for item in items {
+                let new_value = match relief {
+                    Relief::DivideByThree => op.exec(item) / 3,
+                    Relief::Modulo(modulus) => op.exec(item) % modulus,
+                };
+                let next_index = if new_value % dividend == 0 {
+                    divisible_next
                 } else {
-                    monkey.indivisible_next
+                    indivisible_next
                 };
-                self.monkeys[next_index].items.push(new_value);
+                monkeys[next_index].items.push(new_value);
             }
         }
     }
+
+    /// Number of items each monkey inspects over `rounds` rounds.
+    fn inspections(&self, rounds: usize, relief: Relief) -> Vec<usize> {
+        let mut monkeys = self.monkeys.clone();
+        let mut inspections = vec![0; monkeys.len()];
+        for _ in 0..rounds {
+            Self::tick(&mut monkeys, relief, &mut inspections);
+        }
+        inspections
+    }
+
+    /// Product of the numbers of items inspected by the two most active monkeys.
+    fn monkey_business(&self, rounds: usize, relief: Relief) -> usize {
+        let mut inspections = self.inspections(rounds, relief);
+        inspections.sort_unstable_by(|a, b| b.cmp(a));
+        inspections.iter().take(2).product()
+    }
+
+    /// Least common multiple of the divisors the monkeys test, which is the smallest modulus
+    /// that preserves every test.
+    fn modulus(&self) -> usize {
+        self.monkeys.iter().fold(1, |lcm, monkey| {
+            lcm / gcd(lcm, monkey.dividend) * monkey.dividend
+        })
+    }
+}
+
+impl Solution for Game {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    /// The input is the notes on each monkey, in order and separated by blank lines.
+    fn parse(input: &str) -> Result<Self, Error> {
+        lazy_static! {
+            static ref HEADER: Regex = Regex::new(r"^Monkey (\d+):$").unwrap();
+        }
+
+        let mut monkeys = Vec::new();
+        for block in paragraphs(input) {
+            let (header, notes) = block.split_once('\n').unwrap_or((block, ""));
+            let header = header.trim_end();
+            let id = HEADER
+                .captures(header)
+                .and_then(|captures| captures.get(1))
+                .ok_or_else(|| Error::at(header, "expected `Monkey N:`"))?;
+            if parse_number(id.as_str(), "monkey")? != monkeys.len() {
+                return Err(Error::at(
+                    id.as_str(),
+                    format!("expected monkey {}", monkeys.len()),
+                ));
+            }
+
+            monkeys.push(notes.parse::<Monkey>()?);
+        }
+
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for next in [monkey.divisible_next, monkey.indivisible_next] {
+                if next >= monkeys.len() || next == i {
+                    return Err(Error::new(format!(
+                        "monkey {} can't throw to monkey {}",
+                        i, next
+                    )));
+                }
+            }
+        }
+
+        Ok(Self { monkeys })
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        Ok(self.monkey_business(20, Relief::DivideByThree))
+    }
+
+    fn part2(&self) -> Result<usize, Error> {
+        Ok(self.monkey_business(10_000, Relief::Modulo(self.modulus())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+    #[test]
+    fn test_solution() {
+        let game = Game::parse(INPUT).unwrap();
+        assert_eq!(Ok(10605), game.part1());
+        assert_eq!(Ok(2713310158), game.part2());
+
+        let game = Game::parse(&INPUT.replace('\n', "\r\n")).unwrap();
+        assert_eq!(Ok(10605), game.part1());
+    }
+
+    #[test]
+    fn test_monkey_from_str() {
+        let game = Game::parse(INPUT).unwrap();
+        assert_eq!(
+            Monkey {
+                items: vec![54, 65, 75, 74],
+                op: Operation::Add(6),
+                dividend: 19,
+                divisible_next: 2,
+                indivisible_next: 0,
+            },
+            game.monkeys[1]
+        );
+        assert_eq!(Operation::MulSelf, game.monkeys[2].op);
+    }
+
+    #[test]
+    fn test_inspections() {
+        let game = Game::parse(INPUT).unwrap();
+        assert_eq!(
+            vec![101, 95, 7, 105],
+            game.inspections(20, Relief::DivideByThree)
+        );
+
+        let relief = Relief::Modulo(game.modulus());
+        assert_eq!(96577, game.modulus());
+        assert_eq!(vec![2, 4, 3, 6], game.inspections(1, relief));
+        assert_eq!(vec![99, 97, 8, 103], game.inspections(20, relief));
+        assert_eq!(
+            vec![52166, 47830, 1938, 52013],
+            game.inspections(10_000, relief)
+        );
+    }
+
+    #[test]
+    fn test_operation() {
+        assert_eq!(Some(Operation::Add(6)), Operation::new("new = old + 6"));
+        assert_eq!(Some(Operation::Mul(19)), Operation::new("new = old * 19"));
+        assert_eq!(Some(Operation::Mul(2)), Operation::new("new = old + old"));
+        assert_eq!(None, Operation::new("new = old - 3"));
+        assert_eq!(7, Operation::Add(4).exec(3));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let column = |input: &str| {
+            let error = Game::parse(input).err().unwrap().locate(input);
+            error
+                .location()
+                .map(|location| (location.line, location.column))
+        };
+
+        let input = INPUT.replace("old + 6", "old - 6");
+        assert_eq!(Some((10, 14)), column(&input));
+        let input = INPUT.replace("79, 98", "79, x");
+        assert_eq!(Some((2, 23)), column(&input));
+        let input = INPUT.replace("Monkey 1:", "Monkey 2:");
+        assert_eq!(Some((8, 8)), column(&input));
+        let input = INPUT.replace("79, 98", "79, x").replace('\n', "\r\n");
+        assert_eq!(Some((2, 23)), column(&input));
+        assert!(Game::parse(&INPUT.replace("monkey 3", "monkey 4")).is_err());
+    }
 }
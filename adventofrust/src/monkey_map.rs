@@ -3,7 +3,13 @@
 
 #![allow(dead_code)]
 
-use std::{fs::read_to_string, iter::once, num::NonZeroUsize, ops::Deref, str::FromStr};
+use std::{iter::once, num::NonZeroUsize, ops::Deref, str::FromStr};
+
+use crate::{
+    error::Error,
+    solution::{Part, Solution, Trace},
+    utils::paragraphs::paragraphs,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Rotation {
@@ -30,34 +36,48 @@ enum Command {
 }
 
 impl FromStr for Command {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(rotation) = s.chars().next().ok_or(())?.try_into() {
-            Ok(Self::Turn(rotation))
-        } else if let Ok(n) = s.parse() {
-            Ok(Self::Go(n))
-        } else {
-            Err(())
+        let mut chars = s.chars();
+        match (chars.next().map(Rotation::try_from), chars.next()) {
+            (Some(Ok(rotation)), None) => Ok(Self::Turn(rotation)),
+            _ => parse_steps(s).map(Self::Go),
         }
     }
 }
 
+/// Parses the number of tiles to move forward, pointing at the first character that isn't a
+/// digit if there is one.
+fn parse_steps(s: &str) -> Result<NonZeroUsize, Error> {
+    if let Some((i, ch)) = s.char_indices().find(|(_, ch)| !ch.is_ascii_digit()) {
+        return Err(Error::at(
+            &s[i..i + ch.len_utf8()],
+            "expected a number of tiles, L or R",
+        ));
+    }
+    if s.is_empty() {
+        return Err(Error::at(s, "expected a number of tiles"));
+    }
+
+    s.parse()
+        .map_err(|e| Error::at(s, format!("invalid number of tiles: {}", e)))
+}
+
 #[derive(Debug)]
 struct CommandList(Vec<Command>);
 
 impl FromStr for CommandList {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut list = Vec::with_capacity(s.len() / 2);
 
         let mut start = 0;
 
-        for (i, ch) in s.chars().enumerate() {
+        for (i, ch) in s.char_indices() {
             if let Ok(rotation) = Rotation::try_from(ch) {
-                let n = s[start..i].parse().map_err(|_| ())?;
-                list.push(Command::Go(n));
+                list.push(Command::Go(parse_steps(&s[start..i])?));
                 list.push(Command::Turn(rotation));
 
                 start = i + 1;
@@ -65,8 +85,7 @@ impl FromStr for CommandList {
         }
 
         if start < s.len() {
-            let n = s[start..].parse().map_err(|_| ())?;
-            list.push(Command::Go(n));
+            list.push(Command::Go(parse_steps(&s[start..])?));
         }
 
         Ok(CommandList(list))
@@ -81,7 +100,7 @@ impl Deref for CommandList {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Facing {
     Up,
     Down,
@@ -90,6 +109,8 @@ enum Facing {
 }
 
 impl Facing {
+    const MEMBERS: [Self; 4] = [Self::Right, Self::Down, Self::Left, Self::Up];
+
     fn rotate(self, rotation: Rotation) -> Self {
         match (self, rotation) {
             (Facing::Up, Rotation::Clockwise) | (Facing::Down, Rotation::Counter) => Self::Right,
@@ -98,6 +119,16 @@ impl Facing {
             (Facing::Left, Rotation::Counter) | (Facing::Right, Rotation::Clockwise) => Self::Down,
         }
     }
+
+    /// Move of one tile in this direction, as `(x, y)` deltas.
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Facing::Up => (0, -1),
+            Facing::Down => (0, 1),
+            Facing::Left => (-1, 0),
+            Facing::Right => (1, 0),
+        }
+    }
 }
 
 impl From<Facing> for usize {
@@ -111,6 +142,17 @@ impl From<Facing> for usize {
     }
 }
 
+impl From<Facing> for char {
+    fn from(value: Facing) -> Self {
+        match value {
+            Facing::Right => '>',
+            Facing::Down => 'v',
+            Facing::Left => '<',
+            Facing::Up => '^',
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Tile {
     Ground,
@@ -135,7 +177,7 @@ struct Coordinate {
     y: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Player {
     position: Coordinate,
     facing: Facing,
@@ -150,9 +192,127 @@ impl Player {
     }
 }
 
+/// Unit vector along an axis of the space the cube is folded in.
+type Vector = [i64; 3];
+
+fn neg(v: Vector) -> Vector {
+    v.map(|c| -c)
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// A face of the folded cube: where it is in the net, in faces, and which directions of space
+/// its outward normal and the right and down of the board point to.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    column: usize,
+    row: usize,
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Face {
+    fn direction(&self, facing: Facing) -> Vector {
+        match facing {
+            Facing::Right => self.right,
+            Facing::Down => self.down,
+            Facing::Left => neg(self.right),
+            Facing::Up => neg(self.down),
+        }
+    }
+
+    fn facing(&self, direction: Vector) -> Facing {
+        Facing::MEMBERS
+            .into_iter()
+            .find(|&facing| self.direction(facing) == direction)
+            .expect("a direction along a face is one of its facings")
+    }
+
+    /// The face next to this one towards `facing` in the net, at `(column, row)`, which the
+    /// fold rolls over the edge between them.
+    fn roll(&self, facing: Facing, (column, row): (usize, usize)) -> Self {
+        let forward = self.direction(facing);
+        let rotate = |v: Vector| match v {
+            v if v == forward => neg(self.normal),
+            v if v == neg(forward) => self.normal,
+            v => v,
+        };
+
+        Self {
+            column,
+            row,
+            normal: forward,
+            right: rotate(self.right),
+            down: rotate(self.down),
+        }
+    }
+}
+
+/// The board folded into a cube, telling where walking off the edge of a face leads.
+#[derive(Debug, Clone)]
+struct Cube {
+    /// Side of a face, in tiles.
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    /// Where the player at `position`, on the edge of a face and facing off it, lands on the
+    /// next face.
+    fn cross_edge(&self, position: Coordinate, facing: Facing) -> Player {
+        let size = self.size as i64;
+        let face = self
+            .faces
+            .iter()
+            .find(|face| {
+                (face.column, face.row) == (position.x / self.size, position.y / self.size)
+            })
+            .expect("the player is on a face");
+        let forward = face.direction(facing);
+        let next = self
+            .faces
+            .iter()
+            .find(|next| next.normal == forward)
+            .expect("a cube has a face on every side");
+
+        // Coordinates are doubled, so that the centers of the tiles are integers on a cube
+        // spanning -size..=size. Over the edge, the center of the tile moves one unit forward
+        // and one unit into the cube.
+        let (i, j) = (
+            (position.x % self.size) as i64,
+            (position.y % self.size) as i64,
+        );
+        let center = [0, 1, 2].map(|axis| {
+            size * face.normal[axis]
+                + (2 * i + 1 - size) * face.right[axis]
+                + (2 * j + 1 - size) * face.down[axis]
+                + forward[axis]
+                - face.normal[axis]
+        });
+        let (i, j) = (
+            (dot(center, next.right) + size - 1) / 2,
+            (dot(center, next.down) + size - 1) / 2,
+        );
+
+        Player {
+            position: Coordinate {
+                x: next.column * self.size + i as usize,
+                y: next.row * self.size + j as usize,
+            },
+            facing: next.facing(neg(face.normal)),
+        }
+    }
+}
+
+#[derive(Clone)]
 struct Game {
     player: Player,
     board: Vec<Vec<Option<Tile>>>,
+    /// Every position of the player since [`Game::start_trace`], if tracing.
+    trace: Option<Vec<Player>>,
 }
 
 impl Game {
@@ -164,99 +324,201 @@ impl Game {
         self.board.len()
     }
 
-    fn execute(&mut self, command: Command) {
-        fn last_index_before_wall(iter: impl Iterator<Item = (usize, Tile)>) -> Option<usize> {
-            let mut previous_index = None;
-            for (i, tile) in iter {
-                if matches!(tile, Tile::Wall) {
-                    break;
-                }
-
-                previous_index = Some(i);
+    /// Where the player ends up after one step forward on the flat board, ignoring walls. Off
+    /// the edge of the board, the player wraps around to the other side of the row or column.
+    fn step(&self) -> Player {
+        let Player { position, facing } = self.player;
+        let (dx, dy) = facing.offset();
+        let (width, height) = (self.width() as isize, self.height() as isize);
+        let (mut x, mut y) = (position.x as isize, position.y as isize);
+        loop {
+            x = (x + dx).rem_euclid(width);
+            y = (y + dy).rem_euclid(height);
+            if self.tile(x as usize, y as usize).is_some() {
+                return Player {
+                    position: Coordinate {
+                        x: x as usize,
+                        y: y as usize,
+                    },
+                    facing,
+                };
             }
-
-            previous_index
         }
+    }
 
+    /// Executes `command`, moving forward with `step` until a wall blocks the way.
+    fn execute_with(&mut self, command: Command, step: impl Fn(&Self) -> Player) {
         match command {
-            Command::Go(n) => match self.player.facing {
-                Facing::Up => {
-                    let iter = self
-                        .board
-                        .iter()
-                        .enumerate()
-                        .rev()
-                        .cycle()
-                        .skip(self.height() - self.player.position.y)
-                        .filter_map(|(y, row)| {
-                            row.get(self.player.position.x)
-                                .copied()
-                                .flatten()
-                                .map(|x| (y, x))
-                        })
-                        .take(n.into());
-
-                    if let Some(y) = last_index_before_wall(iter) {
-                        self.player.position.y = y;
+            Command::Go(n) => {
+                for _ in 0..n.get() {
+                    let next = step(self);
+                    if matches!(
+                        self.tile(next.position.x, next.position.y),
+                        Some(Tile::Wall)
+                    ) {
+                        break;
                     }
+                    self.player = next;
+                    self.record();
                 }
-                Facing::Down => {
-                    let iter = self
-                        .board
-                        .iter()
-                        .enumerate()
-                        .cycle()
-                        .skip(self.player.position.y + 1)
-                        .filter_map(|(y, row)| {
-                            row.get(self.player.position.x)
-                                .copied()
-                                .flatten()
-                                .map(|x| (y, x))
-                        })
-                        .take(n.into());
-
-                    if let Some(y) = last_index_before_wall(iter) {
-                        self.player.position.y = y;
-                    }
-                }
-                Facing::Left => {
-                    let iter = self.board[self.player.position.y]
-                        .iter()
-                        .enumerate()
-                        .rev()
-                        .cycle()
-                        .skip(self.width() - self.player.position.x)
-                        .filter_map(|(y, &tile)| tile.map(|x| (y, x)))
-                        .take(n.into());
-
-                    if let Some(x) = last_index_before_wall(iter) {
-                        self.player.position.x = x;
-                    }
-                }
-                Facing::Right => {
-                    let iter = self.board[self.player.position.y]
-                        .iter()
-                        .enumerate()
-                        .cycle()
-                        .skip(self.player.position.x + 1)
-                        .filter_map(|(y, &tile)| tile.map(|x| (y, x)))
-                        .take(n.into());
-
-                    if let Some(x) = last_index_before_wall(iter) {
-                        self.player.position.x = x;
-                    }
-                }
-            },
-            Command::Turn(rotation) => self.player.facing = self.player.facing.rotate(rotation),
+            }
+            Command::Turn(rotation) => {
+                self.player.facing = self.player.facing.rotate(rotation);
+                self.record();
+            }
         }
     }
 
+    fn execute(&mut self, command: Command) {
+        self.execute_with(command, Self::step);
+    }
+
     fn run(&mut self, commands: &[Command]) {
         for &command in commands {
             self.execute(command);
         }
     }
 
+    fn tile(&self, x: usize, y: usize) -> Option<Tile> {
+        self.board.get(y)?.get(x).copied().flatten()
+    }
+
+    /// Where the player ends up after one step forward on `cube`, ignoring walls.
+    fn step_on_cube(&self, cube: &Cube) -> Player {
+        let Player { position, facing } = self.player;
+        let (dx, dy) = facing.offset();
+        let next = position
+            .x
+            .checked_add_signed(dx)
+            .zip(position.y.checked_add_signed(dy))
+            .filter(|&(x, y)| self.tile(x, y).is_some());
+
+        match next {
+            Some((x, y)) => Player {
+                position: Coordinate { x, y },
+                facing,
+            },
+            None => cube.cross_edge(position, facing),
+        }
+    }
+
+    fn execute_on_cube(&mut self, cube: &Cube, command: Command) {
+        self.execute_with(command, |game| game.step_on_cube(cube));
+    }
+
+    fn run_on_cube(&mut self, cube: &Cube, commands: &[Command]) {
+        for &command in commands {
+            self.execute_on_cube(cube, command);
+        }
+    }
+
+    /// Starts recording every tile the player visits, beginning with the current one.
+    fn start_trace(&mut self) {
+        self.trace = Some(vec![self.player]);
+    }
+
+    fn record(&mut self) {
+        if let Some(trace) = &mut self.trace {
+            trace.push(self.player);
+        }
+    }
+
+    /// Draws the board like the puzzle statement, with the facing of the player on each tile of
+    /// the trace. When a tile is visited more than once, the last facing is drawn.
+    fn render(&self) -> String {
+        let mut rows = self
+            .board
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|tile| match tile {
+                        Some(Tile::Ground) => '.',
+                        Some(Tile::Wall) => '#',
+                        None => ' ',
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for player in self.trace.iter().flatten() {
+            rows[player.position.y][player.position.x] = player.facing.into();
+        }
+
+        rows.iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Folds the board into a cube. The board must be the net of a cube: 6 square faces of the
+    /// same size joined by their sides, in any of the 11 possible arrangements.
+    fn fold(&self) -> Result<Cube, Error> {
+        let tiles = self.board.iter().flatten().flatten().count();
+        let size = (1..)
+            .take_while(|size| 6 * size * size <= tiles)
+            .last()
+            .filter(|size| 6 * size * size == tiles)
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "a board of {} tiles can't be folded into a cube",
+                    tiles
+                ))
+            })?;
+        let not_a_net = || Error::new("the board isn't the net of a cube");
+
+        let mut blocks = Vec::new();
+        for row in 0..self.height().div_ceil(size) {
+            for column in 0..self.width().div_ceil(size) {
+                let filled = (row * size..(row + 1) * size)
+                    .flat_map(|y| (column * size..(column + 1) * size).map(move |x| (x, y)))
+                    .filter(|&(x, y)| self.tile(x, y).is_some())
+                    .count();
+                if filled == size * size {
+                    blocks.push((column, row));
+                } else if filled > 0 {
+                    return Err(not_a_net());
+                }
+            }
+        }
+
+        let Some(&(column, row)) = blocks.first() else {
+            return Err(not_a_net());
+        };
+        let mut faces = vec![Face {
+            column,
+            row,
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        }];
+        let mut i = 0;
+        while let Some(&face) = faces.get(i) {
+            for facing in Facing::MEMBERS {
+                let (dx, dy) = facing.offset();
+                let neighbor = face
+                    .column
+                    .checked_add_signed(dx)
+                    .zip(face.row.checked_add_signed(dy))
+                    .filter(|block| blocks.contains(block));
+                if let Some(neighbor) = neighbor {
+                    if faces.iter().all(|f| (f.column, f.row) != neighbor) {
+                        faces.push(face.roll(facing, neighbor));
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        let mut normals = faces.iter().map(|face| face.normal).collect::<Vec<_>>();
+        normals.sort_unstable();
+        normals.dedup();
+        if blocks.len() != 6 || faces.len() != 6 || normals.len() != 6 {
+            return Err(not_a_net());
+        }
+
+        Ok(Cube { size, faces })
+    }
+
     fn get_answer(&self) -> usize {
         let Coordinate { x, y } = self.player.position;
         1000 * (y + 1) + 4 * (x + 1) + usize::from(self.player.facing)
@@ -264,69 +526,92 @@ impl Game {
 }
 
 impl FromStr for Game {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_row = |line: &str| {
+            line.char_indices()
+                .map(|(i, ch)| match ch {
+                    ' ' => Ok(None),
+                    _ => Tile::try_from(ch).map(Some).map_err(|_| {
+                        Error::at(&line[i..i + ch.len_utf8()], "expected ' ', '.' or '#'")
+                    }),
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
         let mut lines = s.lines();
-        let first_line = lines.next().ok_or(())?;
-        let first_row: Vec<Option<Tile>> = first_line
-            .chars()
-            .map(|ch| Tile::try_from(ch).ok())
-            .collect();
+        let first_line = lines
+            .next()
+            .ok_or_else(|| Error::at(s, "the board is empty"))?;
+        let first_row = parse_row(first_line)?;
         let x = first_row.partition_point(|x| x.is_none());
-        let width = first_row.len();
-        let iter = lines.map(|line| {
-            let mut row = line
-                .chars()
-                .map(|ch| Tile::try_from(ch).ok())
-                .collect::<Vec<_>>();
+        let rows = lines.map(parse_row).collect::<Result<Vec<_>, Error>>()?;
+        let mut board = once(first_row).chain(rows).collect::<Vec<_>>();
+
+        // Trailing spaces are often trimmed, so rows are padded to the longest one.
+        let width = board.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut board {
             row.resize(width, None);
-            row
-        });
-        let board = once(first_row).chain(iter).collect();
+        }
 
         Ok(Self {
             player: Player::new(x),
             board,
+            trace: None,
         })
     }
 }
 
-const BOARD_FILENAME: &str = "input/monkey_map_input_board.txt";
-const COMMANDS_FILENAME: &str = "input/monkey_map_input_commands.txt";
-
-pub fn main() -> Result<(), String> {
-    let commands: CommandList = read_to_string(COMMANDS_FILENAME)
-        .map_err(|e| e.to_string())?
-        .trim()
-        .parse()
-        .map_err(|_| "error parsing commands")?;
-
-    let mut game: Game = read_to_string(BOARD_FILENAME)
-        .map_err(|e| e.to_string())?
-        .parse()
-        .map_err(|_| "error parsing commands")?;
-
-    let board_str: String = game
-        .board
-        .iter()
-        .flat_map(|row| {
-            row.iter()
-                .map(|x| match x {
-                    Some(Tile::Wall) => '#',
-                    Some(Tile::Ground) => '.',
-                    None => 'o',
-                })
-                .chain(once('\n'))
-        })
-        .collect();
-    println!("{}", board_str);
+pub struct Notes {
+    game: Game,
+    commands: CommandList,
+}
 
-    game.run(&commands);
+impl Trace for Notes {
+    /// The board with the path followed in `part` drawn on it, as in the puzzle statement.
+    fn trace(&self, part: Part) -> Result<String, Error> {
+        let mut game = self.game.clone();
+        game.start_trace();
+        match part {
+            Part::One => game.run(&self.commands),
+            Part::Two => {
+                let cube = game.fold()?;
+                game.run_on_cube(&cube, &self.commands);
+            }
+        }
+        Ok(game.render())
+    }
+}
 
-    println!("{}", game.get_answer());
+impl Solution for Notes {
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    Ok(())
+    /// The input is the board followed by a blank line and then the path.
+    fn parse(input: &str) -> Result<Self, Error> {
+        let [board_str, commands_str] = paragraphs(input)[..] else {
+            return Err(Error::new("expected the board, a blank line and the path"));
+        };
+
+        let commands = commands_str.trim().parse()?;
+        let game = board_str.parse()?;
+
+        Ok(Self { game, commands })
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        let mut game = self.game.clone();
+        game.run(&self.commands);
+        Ok(game.get_answer())
+    }
+
+    fn part2(&self) -> Result<usize, Error> {
+        let mut game = self.game.clone();
+        let cube = game.fold()?;
+        game.run_on_cube(&cube, &self.commands);
+        Ok(game.get_answer())
+    }
 }
 
 #[cfg(test)]
@@ -470,4 +755,161 @@ oooooooo......#.\n",
 
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn test_cube() {
+        let notes = Notes::parse(&format!("{}\n\n10R5L5R10L4R5L5", INPUT)).unwrap();
+        assert_eq!(6032, notes.part1().unwrap());
+        assert_eq!(5031, notes.part2().unwrap());
+
+        let crlf = format!("{}\n\n10R5L5R10L4R5L5\n", INPUT).replace('\n', "\r\n");
+        let crlf_notes = Notes::parse(&crlf).unwrap();
+        assert_eq!(6032, crlf_notes.part1().unwrap());
+        assert_eq!(5031, crlf_notes.part2().unwrap());
+
+        // From A to B in the description of part 2.
+        let mut game = notes.game.clone();
+        let cube = game.fold().unwrap();
+        game.player = Player {
+            position: Coordinate { x: 11, y: 5 },
+            facing: Facing::Right,
+        };
+        assert_eq!(
+            Player {
+                position: Coordinate { x: 14, y: 8 },
+                facing: Facing::Down
+            },
+            game.step_on_cube(&cube)
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let notes = Notes::parse(&format!("{}\n\n10R5L5R10L4R5L5", INPUT)).unwrap();
+
+        assert_eq!(
+            "        >>v#    
+        .#v.    
+        #.v.    
+        ..v.    
+...#...v..v#    
+>>>v...>#.>>    
+..#v...#....    
+...>>>>v..#.    
+        ...#....
+        .....#..
+        .#......
+        ......#.",
+            notes.trace(Part::One).unwrap()
+        );
+        assert_eq!(
+            "        >>v#    
+        .#v.    
+        #.v.    
+        ..v.    
+...#..^...v#    
+.>>>>>^.#.>>    
+.^#....#....    
+.^........#.    
+        ...#..v.
+        .....#v.
+        .#v<<<<.
+        ..v...#.",
+            notes.trace(Part::Two).unwrap()
+        );
+    }
+
+    /// The 11 nets of a cube, with `#` for a face.
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    /// A wall-less board with faces of `size` tiles laid out as `net`.
+    fn board(net: &str, size: usize) -> Game {
+        let board = net
+            .lines()
+            .flat_map(|line| {
+                let row = line
+                    .chars()
+                    .flat_map(|c| [if c == '#' { '.' } else { ' ' }; 8].into_iter().take(size))
+                    .collect::<String>();
+                vec![row; size]
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        board.parse().unwrap()
+    }
+
+    #[test]
+    fn test_fold_nets() {
+        for net in NETS {
+            for size in [1, 3, 4] {
+                let mut game = board(net, size);
+                let cube = game.fold().unwrap_or_else(|e| panic!("{}: {}", net, e));
+
+                // Going straight around the cube leads back to the start, and every step can be
+                // walked back.
+                let tiles = (0..game.height())
+                    .flat_map(|y| (0..game.width()).map(move |x| Coordinate { x, y }))
+                    .filter(|&Coordinate { x, y }| game.tile(x, y).is_some())
+                    .collect::<Vec<_>>();
+                for position in tiles {
+                    for facing in Facing::MEMBERS {
+                        let start = Player { position, facing };
+                        game.player = start;
+                        for _ in 0..4 * size {
+                            game.player = game.step_on_cube(&cube);
+                        }
+                        assert_eq!(start, game.player, "{}", net);
+
+                        game.player = start;
+                        let step = game.step_on_cube(&cube);
+                        game.player = Player {
+                            facing: step
+                                .facing
+                                .rotate(Rotation::Clockwise)
+                                .rotate(Rotation::Clockwise),
+                            ..step
+                        };
+                        let back = game.step_on_cube(&cube);
+                        assert_eq!(start.position, back.position, "{}", net);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fold_errors() {
+        assert!(board("######", 2).fold().is_err());
+        assert!(board("####\n##..", 2).fold().is_err());
+        assert!(board("#.#\n###\n.#.", 1).fold().is_err());
+        assert!("...\n...".parse::<Game>().unwrap().fold().is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let column = |input: &str| {
+            let error = Notes::parse(input).err().unwrap().locate(input);
+            error
+                .location()
+                .map(|location| (location.line, location.column))
+        };
+
+        assert_eq!(Some((2, 3)), column("..#\n.#x\n\n10R5"));
+        assert_eq!(Some((4, 5)), column("..#\n.#.\n\n10R5X5"));
+        assert_eq!(Some((4, 4)), column("..#\n.#.\n\n10RL5"));
+        assert_eq!(Some((4, 4)), column("..#\r\n.#.\r\n\r\n10RL5\r\n"));
+        assert!(Notes::parse("..#\n.#.\n10R5").is_err());
+    }
 }
@@ -2,46 +2,47 @@
 use std::{
     cell::RefCell,
     fmt::{Display, Formatter},
-    io::{self, Read},
     ops::Deref,
     rc::{Rc, Weak},
 };
 
+use crate::{error::Error, solution::Solution};
+
 enum Command<'a> {
     CD(&'a str),
     LS(LsResult<'a>),
 }
 
 impl<'a> TryFrom<&'a str> for Command<'a> {
-    type Error = String;
+    type Error = Error;
 
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        let ferrstr = || format!("{} is not a valid command.", s);
-        let (command_str, command_result) = s.split_once('\n').ok_or_else(ferrstr)?;
+        let ferr = || Error::at(s.lines().next().unwrap_or(s).trim(), "not a valid command");
+        let (command_str, command_result) = s.split_once('\n').ok_or_else(ferr)?;
         let mut s = command_str.split_whitespace();
-        match s.next().ok_or_else(ferrstr)? {
-            "cd" => Ok(Self::CD(s.next().ok_or_else(ferrstr)?)),
+        match s.next().ok_or_else(ferr)? {
+            "cd" => Ok(Self::CD(s.next().ok_or_else(ferr)?)),
             "ls" => Ok(Self::LS(LsResult::try_from(command_result)?)),
-            _ => Err(ferrstr()),
+            _ => Err(ferr()),
         }
     }
 }
 
-type DirectoryRef<'a> = Rc<RefCell<Directory<'a>>>;
-type DirectoryWeak<'a> = Weak<RefCell<Directory<'a>>>;
+type DirectoryRef = Rc<RefCell<Directory>>;
+type DirectoryWeak = Weak<RefCell<Directory>>;
 
-pub struct Directory<'a> {
-    name: &'a str,
+pub struct Directory {
+    name: String,
     size: usize,
     total_size: Option<usize>,
-    parent: Option<DirectoryWeak<'a>>,
-    subdirs: Vec<DirectoryRef<'a>>,
+    parent: Option<DirectoryWeak>,
+    subdirs: Vec<DirectoryRef>,
 }
 
-impl<'a> Directory<'a> {
+impl Directory {
     fn root() -> Self {
         Self {
-            name: "/",
+            name: "/".to_owned(),
             size: 0,
             total_size: None,
             parent: None,
@@ -49,9 +50,9 @@ impl<'a> Directory<'a> {
         }
     }
 
-    fn new(name: &'a str, parent: DirectoryWeak<'a>) -> Self {
+    fn new(name: &str, parent: DirectoryWeak) -> Self {
         Self {
-            name,
+            name: name.to_owned(),
             size: 0,
             total_size: None,
             parent: Some(parent),
@@ -109,11 +110,24 @@ impl<'a> Directory<'a> {
 
         current_best
     }
+
+    /// Sum of the total sizes of this directory and its descendants, counting only those of at
+    /// most `limit`.
+    fn sum_small_dirs(&mut self, limit: usize) -> usize {
+        let total_size = self.total_size();
+        let own = if total_size <= limit { total_size } else { 0 };
+
+        own + self
+            .subdirs
+            .iter()
+            .map(|subdir| subdir.borrow_mut().sum_small_dirs(limit))
+            .sum::<usize>()
+    }
 }
 
-impl<'a> Display for Directory<'a> {
+impl Display for Directory {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fn aux<'a>(dir: impl Deref<Target = Directory<'a>>, depth: usize, out: &mut Vec<String>) {
+        fn aux(dir: impl Deref<Target = Directory>, depth: usize, out: &mut Vec<String>) {
             let s = if let Some(total_size) = dir.total_size {
                 format!(
                     "{space:width$}- dir {} {} | {}",
@@ -145,12 +159,12 @@ impl<'a> Display for Directory<'a> {
     }
 }
 
-struct FileSystem<'a> {
-    root: DirectoryRef<'a>,
-    cwd: DirectoryRef<'a>,
+pub struct FileSystem {
+    root: DirectoryRef,
+    cwd: DirectoryRef,
 }
 
-impl<'a> FileSystem<'a> {
+impl FileSystem {
     fn new() -> Self {
         let root = Rc::new(RefCell::new(Directory::root()));
         Self {
@@ -159,7 +173,7 @@ impl<'a> FileSystem<'a> {
         }
     }
 
-    fn add_child(&mut self, dir_name: &'a str) {
+    fn add_child(&mut self, dir_name: &str) {
         let mut cwd = self.cwd.borrow_mut();
         if !cwd.has_subdir(dir_name) {
             cwd.subdirs.push(Rc::new(RefCell::new(Directory::new(
@@ -169,7 +183,7 @@ impl<'a> FileSystem<'a> {
         }
     }
 
-    fn update(&mut self, ls_result: &LsResult<'a>) {
+    fn update(&mut self, ls_result: &LsResult<'_>) {
         for &dir_name in &ls_result.subdirs {
             self.add_child(dir_name)
         }
@@ -190,7 +204,7 @@ impl<'a> FileSystem<'a> {
             .expect("we own the root node so this should always be valid as long as we're valid.");
     }
 
-    fn cd_dir(&mut self, dir_name: &str) -> Result<(), String> {
+    fn cd_dir(&mut self, dir_name: &str) -> Result<(), Error> {
         let Some(new_cwd) = self
             .cwd
             .borrow()
@@ -199,14 +213,20 @@ impl<'a> FileSystem<'a> {
             .find(|x| x.borrow().name == dir_name)
             .cloned()
         else {
-            return Err(format!("directory {} does not contain sub-directory {}", self.cwd.borrow().name, dir_name));
+            return Err(Error::at(
+                dir_name,
+                format!(
+                    "directory {} has no such sub-directory",
+                    self.cwd.borrow().name
+                ),
+            ));
         };
 
         self.cwd = new_cwd;
         Ok(())
     }
 
-    fn execute(&mut self, command: Command<'a>) -> Result<(), String> {
+    fn execute(&mut self, command: Command<'_>) -> Result<(), Error> {
         match command {
             Command::CD("/") => {
                 self.cd_root();
@@ -224,7 +244,7 @@ impl<'a> FileSystem<'a> {
         }
     }
 
-    fn get_answer(&mut self) -> usize {
+    fn get_answer(&self) -> usize {
         const TOTAL_DISK_SPACE: usize = 70000000;
         const REQUIRED_SPACE: usize = 30000000;
 
@@ -234,12 +254,12 @@ impl<'a> FileSystem<'a> {
     }
 }
 
-impl Display for FileSystem<'_> {
+impl Display for FileSystem {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str("file structure:\n")?;
         writeln!(f, "{}", self.root.borrow())?;
         f.write_str("current directory: ")?;
-        f.write_str(self.cwd.borrow().name)
+        f.write_str(&self.cwd.borrow().name)
     }
 }
 
@@ -256,56 +276,56 @@ impl<'a> LsResult<'a> {
         }
     }
 
-    fn process_line(&mut self, line: &'a str) -> Option<()> {
+    fn process_line(&mut self, line: &'a str) -> Result<(), Error> {
+        let ferr = || Error::at(line, "expected `dir NAME` or `SIZE NAME`");
         let mut iter = line.split_whitespace();
-        let head = iter.next()?;
+        let head = iter.next().ok_or_else(ferr)?;
         if head == "dir" {
-            self.subdirs.push(iter.next()?);
-            Some(())
+            self.subdirs.push(iter.next().ok_or_else(ferr)?);
+            Ok(())
         } else if let Ok(item_size) = head.parse::<usize>() {
             self.size += item_size;
-            Some(())
+            Ok(())
         } else {
-            None
+            Err(Error::at(head, "expected `dir` or a file size"))
         }
     }
 }
 
 impl<'a> TryFrom<&'a str> for LsResult<'a> {
-    type Error = String;
+    type Error = Error;
 
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         let mut ls_result = LsResult::new();
 
         for line in s.lines() {
-            if ls_result.process_line(line).is_none() {
-                return Err(format!(
-                    "malformed `ls` result: \"{}\": failed to parse line: \"{}\"",
-                    s, line
-                ));
-            }
+            ls_result.process_line(line)?;
         }
 
         Ok(ls_result)
     }
 }
 
-pub fn main() -> Result<(), String> {
-    let mut working_directory = FileSystem::new();
+impl Solution for FileSystem {
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let mut input = String::new();
-    io::stdin()
-        .read_to_string(&mut input)
-        .map_err(|e| e.to_string())?;
+    fn parse(input: &str) -> Result<Self, Error> {
+        let mut working_directory = FileSystem::new();
 
-    for s in input.split('$').skip_while(|s| s.is_empty()) {
-        let command = Command::try_from(s)?;
-        working_directory.execute(command)?;
+        for s in input.split('$').skip_while(|s| s.is_empty()) {
+            let command = Command::try_from(s)?;
+            working_directory.execute(command)?;
+        }
+
+        Ok(working_directory)
     }
 
-    working_directory.root.borrow_mut().total_size();
-    println!("{}", working_directory);
-    println!("{}", working_directory.get_answer());
+    fn part1(&self) -> Result<usize, Error> {
+        Ok(self.root.borrow_mut().sum_small_dirs(100000))
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<usize, Error> {
+        Ok(self.get_answer())
+    }
 }
@@ -1,27 +1,28 @@
 #![allow(dead_code)]
 use std::{
     fmt::{Display, Formatter, Write},
-    io::{self, Read},
     num::NonZeroUsize,
     ops::{Index, IndexMut},
 };
 
+use crate::{error::Error, solution::Solution};
+
 enum Command<'a> {
     CD(&'a str),
     LS(LsResult<'a>),
 }
 
 impl<'a> TryFrom<&'a str> for Command<'a> {
-    type Error = String;
+    type Error = Error;
 
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        let ferrstr = || format!("{} is not a valid command.", s);
-        let (command_str, command_result) = s.split_once('\n').ok_or_else(ferrstr)?;
+        let ferr = || Error::at(s.lines().next().unwrap_or(s).trim(), "not a valid command");
+        let (command_str, command_result) = s.split_once('\n').ok_or_else(ferr)?;
         let mut s = command_str.split_whitespace();
-        match s.next().ok_or_else(ferrstr)? {
-            "cd" => Ok(Self::CD(s.next().ok_or_else(ferrstr)?)),
+        match s.next().ok_or_else(ferr)? {
+            "cd" => Ok(Self::CD(s.next().ok_or_else(ferr)?)),
             "ls" => Ok(Self::LS(LsResult::try_from(command_result)?)),
-            _ => Err(ferrstr()),
+            _ => Err(ferr()),
         }
     }
 }
@@ -39,64 +40,58 @@ impl<'a> LsResult<'a> {
         }
     }
 
-    fn process_line(&mut self, line: &'a str) -> Option<()> {
+    fn process_line(&mut self, line: &'a str) -> Result<(), Error> {
+        let ferr = || Error::at(line, "expected `dir NAME` or `SIZE NAME`");
         let mut iter = line.split_whitespace();
-        let head = iter.next()?;
+        let head = iter.next().ok_or_else(ferr)?;
         if head == "dir" {
-            self.subdirs.push(iter.next()?);
-            Some(())
+            self.subdirs.push(iter.next().ok_or_else(ferr)?);
+            Ok(())
         } else if let Ok(item_size) = head.parse::<usize>() {
             self.size += item_size;
-            Some(())
+            Ok(())
         } else {
-            None
+            Err(Error::at(head, "expected `dir` or a file size"))
         }
     }
 }
 
 impl<'a> TryFrom<&'a str> for LsResult<'a> {
-    type Error = String;
+    type Error = Error;
 
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         let mut ls_result = LsResult::new();
 
         for line in s.lines() {
-            if ls_result.process_line(line).is_none() {
-                return Err(format!(
-                    "malformed `ls` result: \"{}\": failed to parse line: \"{}\"",
-                    s, line
-                ));
-            }
+            ls_result.process_line(line)?;
         }
 
         Ok(ls_result)
     }
 }
 
-struct Directory<'a> {
-    name: &'a str,
+pub struct Directory {
+    name: String,
     size: usize,
     total_size: Option<usize>,
     parent_index: Option<usize>,
     subdir_indices: Vec<usize>,
 }
 
-impl Directory<'_> {
+impl Directory {
     fn root() -> Self {
         Self {
-            name: "/",
+            name: "/".to_owned(),
             size: 0,
             total_size: None,
             parent_index: None,
             subdir_indices: Vec::new(),
         }
     }
-}
 
-impl<'a> Directory<'a> {
-    fn new(name: &'a str, parent_index: usize) -> Self {
+    fn new(name: &str, parent_index: usize) -> Self {
         Self {
-            name,
+            name: name.to_owned(),
             size: 0,
             total_size: None,
             parent_index: Some(parent_index),
@@ -105,12 +100,12 @@ impl<'a> Directory<'a> {
     }
 }
 
-struct FileSystem<'a> {
-    directories: Vec<Directory<'a>>,
+pub struct FileSystem {
+    directories: Vec<Directory>,
     cwd_index: usize,
 }
 
-impl FileSystem<'_> {
+impl FileSystem {
     const ROOT_INDEX: usize = 0;
 
     fn new() -> Self {
@@ -134,15 +129,14 @@ impl FileSystem<'_> {
         }
     }
 
-    fn cd_dir(&mut self, dir_name: &str) -> Result<(), String> {
+    fn cd_dir(&mut self, dir_name: &str) -> Result<(), Error> {
         if let Some(i) = self.find_subdir_index(dir_name) {
             self.cwd_index = i;
             Ok(())
         } else {
-            Err(format!(
-                "directory {} does not contain sub-directory {}",
-                self.cwd().name,
-                dir_name
+            Err(Error::at(
+                dir_name,
+                format!("directory {} has no such sub-directory", self.cwd().name),
             ))
         }
     }
@@ -171,6 +165,13 @@ impl FileSystem<'_> {
         sum
     }
 
+    /// Total size computed by an earlier call to `total_size` on the directory or an ancestor.
+    fn cached_total_size(&self, dir_index: usize) -> usize {
+        self[dir_index]
+            .total_size
+            .expect("total sizes are computed when parsing")
+    }
+
     fn clear_total_size(&mut self, dir_index: usize) {
         let dir = self.index_mut(dir_index);
         if dir.total_size.is_none() {
@@ -186,7 +187,7 @@ impl FileSystem<'_> {
 
     fn riddle_me_dirs(&self, dir_index: usize, target: usize, mut current_best: usize) -> usize {
         let dir = &self[dir_index];
-        let total_size = dir.total_size.unwrap();
+        let total_size = self.cached_total_size(dir_index);
 
         if total_size < target {
             return current_best;
@@ -203,11 +204,11 @@ impl FileSystem<'_> {
         current_best
     }
 
-    fn riddle_me_this(&mut self) -> Option<NonZeroUsize> {
+    fn riddle_me_this(&self) -> Option<NonZeroUsize> {
         const TOTAL_DISK_SPACE: usize = 70000000;
         const REQUIRED_SPACE: usize = 30000000;
 
-        let free_space = TOTAL_DISK_SPACE - self.total_size(Self::ROOT_INDEX);
+        let free_space = TOTAL_DISK_SPACE - self.cached_total_size(Self::ROOT_INDEX);
         let result = self.riddle_me_dirs(Self::ROOT_INDEX, REQUIRED_SPACE - free_space, usize::MAX);
 
         if result == usize::MAX {
@@ -218,12 +219,12 @@ impl FileSystem<'_> {
     }
 }
 
-impl<'a> FileSystem<'a> {
-    fn cwd_mut(&mut self) -> &mut Directory<'a> {
+impl FileSystem {
+    fn cwd_mut(&mut self) -> &mut Directory {
         self.directories.index_mut(self.cwd_index)
     }
 
-    fn update(&mut self, ls_result: &LsResult<'a>) {
+    fn update(&mut self, ls_result: &LsResult<'_>) {
         for &dir_name in &ls_result.subdirs {
             self.add_child(dir_name);
         }
@@ -232,7 +233,7 @@ impl<'a> FileSystem<'a> {
         self.clear_total_size(self.cwd_index);
     }
 
-    fn add_child(&mut self, dir_name: &'a str) {
+    fn add_child(&mut self, dir_name: &str) {
         if self.find_subdir_index(dir_name).is_some() {
             return;
         }
@@ -243,7 +244,7 @@ impl<'a> FileSystem<'a> {
         self.cwd_mut().subdir_indices.push(i);
     }
 
-    fn execute(&mut self, command: Command<'a>) -> Result<(), String> {
+    fn execute(&mut self, command: Command<'_>) -> Result<(), Error> {
         match command {
             Command::CD("/") => {
                 self.cd_root();
@@ -290,21 +291,21 @@ impl<'a> FileSystem<'a> {
     }
 }
 
-impl<'a> Index<usize> for FileSystem<'a> {
-    type Output = Directory<'a>;
+impl Index<usize> for FileSystem {
+    type Output = Directory;
 
     fn index(&self, index: usize) -> &Self::Output {
         self.directories.index(index)
     }
 }
 
-impl<'a> IndexMut<usize> for FileSystem<'a> {
+impl IndexMut<usize> for FileSystem {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         self.directories.index_mut(index)
     }
 }
 
-impl Display for FileSystem<'_> {
+impl Display for FileSystem {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut strings = Vec::new();
         self.fmt_aux(Self::ROOT_INDEX, 0, &mut strings);
@@ -313,30 +314,36 @@ impl Display for FileSystem<'_> {
         f.write_str(strings.join("\n").as_str())?;
         f.write_char('\n')?;
         f.write_str("current directory: ")?;
-        f.write_str(self.cwd().name)
+        f.write_str(&self.cwd().name)
     }
 }
 
-pub fn main() -> Result<(), String> {
-    let mut working_directory = FileSystem::new();
+impl Solution for FileSystem {
+    type Answer1 = usize;
+    type Answer2 = NonZeroUsize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        let mut working_directory = FileSystem::new();
 
-    let mut input = String::new();
-    io::stdin()
-        .read_to_string(&mut input)
-        .map_err(|e| e.to_string())?;
+        for s in input.split('$').skip_while(|s| s.is_empty()) {
+            let command = Command::try_from(s)?;
+            working_directory.execute(command)?;
+        }
+
+        working_directory.total_size(Self::ROOT_INDEX);
 
-    for s in input.split('$').skip_while(|s| s.is_empty()) {
-        let command = Command::try_from(s)?;
-        working_directory.execute(command)?;
+        Ok(working_directory)
     }
 
-    working_directory.total_size(FileSystem::ROOT_INDEX);
-    println!("{}", working_directory);
-    if let Some(result) = working_directory.riddle_me_this() {
-        println!("{}", result.get());
-    } else {
-        println!("shit doesn't work.");
+    fn part1(&self) -> Result<usize, Error> {
+        Ok((0..self.directories.len())
+            .map(|i| self.cached_total_size(i))
+            .filter(|&total_size| total_size <= 100000)
+            .sum())
     }
 
-    Ok(())
+    fn part2(&self) -> Result<NonZeroUsize, Error> {
+        self.riddle_me_this()
+            .ok_or_else(|| Error::new("no directory is big enough to free up the required space"))
+    }
 }
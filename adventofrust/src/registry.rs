@@ -0,0 +1,227 @@
+//! Index of every puzzle solved in this crate, so they can be looked up and run by name or by
+//! day number instead of by editing `main`.
+
+use std::path::PathBuf;
+
+use crate::{
+    blizzard_basin, calorie_counting, camp_cleanup, cathode_ray_tube,
+    error::Error,
+    full_of_hot_air,
+    input::InputDir,
+    monkey_in_the_middle, monkey_map, no_space_left_on_device, no_space_left_on_device_arena,
+    rock_paper_scissor, rope_bridge, rucksack_reorganization,
+    scaffold::Layout,
+    solution::{self, Part, Solution, Solve, Trace},
+    supply_stacks, treetop_tree_house, tuning_trouble, unstable_difusion,
+};
+
+type Parser = fn(&str) -> Result<Box<dyn Solve>, Error>;
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solve>, Error> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+type Tracer = fn(&str, Part) -> Result<String, Error>;
+
+fn trace<S: Trace>(input: &str, part: Part) -> Result<String, Error> {
+    S::parse(input)?.trace(part)
+}
+
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub day: u8,
+    pub name: &'static str,
+    /// File name, without extension, of the puzzle input; alternative solutions of the same
+    /// puzzle share their input.
+    pub input: &'static str,
+    pub title: &'static str,
+    parser: Parser,
+    is_solved: fn(Part) -> bool,
+    tracer: Option<Tracer>,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution + 'static>(
+        day: u8,
+        name: &'static str,
+        title: &'static str,
+    ) -> Self {
+        Self {
+            day,
+            name,
+            input: name,
+            title,
+            parser: parse::<S>,
+            is_solved: solution::is_solved::<S>,
+            tracer: None,
+        }
+    }
+
+    pub const fn with_input(self, input: &'static str) -> Self {
+        Self { input, ..self }
+    }
+
+    /// Lets the runner show how `S` reaches its answers, see [`Trace`].
+    pub const fn with_trace<S: Trace + 'static>(self) -> Self {
+        Self {
+            tracer: Some(trace::<S>),
+            ..self
+        }
+    }
+
+    /// Parses `input`; errors are located in `input` and tagged with the puzzle name.
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Solve>, Error> {
+        (self.parser)(input).map_err(|e| e.locate(input).in_puzzle(self.name))
+    }
+
+    pub fn is_solved(&self, part: Part) -> bool {
+        (self.is_solved)(part)
+    }
+
+    pub fn can_trace(&self) -> bool {
+        self.tracer.is_some()
+    }
+
+    /// Parses `input` and traces `part` of it; errors are reported as by [`Puzzle::parse`].
+    pub fn trace(&self, input: &str, part: Part) -> Result<String, Error> {
+        let tracer = self
+            .tracer
+            .ok_or_else(|| Error::new(format!("{} cannot be traced", self.name)))?;
+        tracer(input, part).map_err(|e| e.locate(input).in_puzzle(self.name))
+    }
+
+    /// Where the puzzle input is expected to be in `dir` when no path is given explicitly.
+    pub fn input_path(&self, dir: &InputDir) -> PathBuf {
+        dir.path(self.day, self.input)
+    }
+}
+
+pub const INPUT_DIR: InputDir = InputDir {
+    root: concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+    pattern: "{input}.txt",
+};
+
+/// All puzzles, ordered by day.
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<calorie_counting::Inventory>(1, "calorie_counting", "Calorie Counting"),
+    Puzzle::new::<rock_paper_scissor::StrategyGuide>(
+        2,
+        "rock_paper_scissor",
+        "Rock Paper Scissors",
+    ),
+    Puzzle::new::<rucksack_reorganization::Rucksacks>(
+        3,
+        "rucksack_reorganization",
+        "Rucksack Reorganization",
+    ),
+    Puzzle::new::<camp_cleanup::Assignments>(4, "camp_cleanup", "Camp Cleanup"),
+    Puzzle::new::<supply_stacks::Procedure>(5, "supply_stacks", "Supply Stacks"),
+    Puzzle::new::<tuning_trouble::Datastream>(6, "tuning_trouble", "Tuning Trouble"),
+    Puzzle::new::<no_space_left_on_device::FileSystem>(
+        7,
+        "no_space_left_on_device",
+        "No Space Left On Device",
+    ),
+    Puzzle::new::<no_space_left_on_device_arena::FileSystem>(
+        7,
+        "no_space_left_on_device_arena",
+        "No Space Left On Device (arena)",
+    )
+    .with_input("no_space_left_on_device"),
+    Puzzle::new::<treetop_tree_house::Board>(8, "treetop_tree_house", "Treetop Tree House"),
+    Puzzle::new::<rope_bridge::Motions>(9, "rope_bridge", "Rope Bridge"),
+    Puzzle::new::<cathode_ray_tube::Program>(10, "cathode_ray_tube", "Cathode-Ray Tube"),
+    Puzzle::new::<monkey_in_the_middle::Game>(11, "monkey_in_the_middle", "Monkey in the Middle"),
+    Puzzle::new::<monkey_map::Notes>(22, "monkey_map", "Monkey Map")
+        .with_trace::<monkey_map::Notes>(),
+    Puzzle::new::<unstable_difusion::Board>(23, "unstable_difusion", "Unstable Diffusion"),
+    Puzzle::new::<blizzard_basin::Board>(24, "blizzard_basin", "Blizzard Basin"),
+    Puzzle::new::<full_of_hot_air::Requirements>(25, "full_of_hot_air", "Full of Hot Air"),
+];
+
+/// The puzzles of one year of Advent of Code, and where their inputs are kept.
+#[derive(Clone, Copy)]
+pub struct Calendar {
+    pub year: u16,
+    pub puzzles: &'static [Puzzle],
+    pub input_dir: InputDir,
+    pub layout: Layout,
+}
+
+impl Calendar {
+    /// Finds the puzzles matching `query`, which is either a puzzle name or a day number.
+    /// Several puzzles can share a day when there are alternative solutions.
+    pub fn select(&self, query: &str) -> Vec<&'static Puzzle> {
+        match query.parse::<u8>() {
+            Ok(day) => self.puzzles.iter().filter(|p| p.day == day).collect(),
+            Err(_) => self.puzzles.iter().filter(|p| p.name == query).collect(),
+        }
+    }
+}
+
+pub const CALENDAR: Calendar = Calendar {
+    year: 2022,
+    puzzles: PUZZLES,
+    input_dir: INPUT_DIR,
+    layout: Layout {
+        root: env!("CARGO_MANIFEST_DIR"),
+        lib: "adventofrust",
+        binaries: false,
+    },
+};
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::*;
+    use crate::answers;
+
+    #[test]
+    fn test_select() {
+        assert_eq!(
+            vec!["blizzard_basin"],
+            CALENDAR
+                .select("24")
+                .into_iter()
+                .map(|p| p.name)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["no_space_left_on_device", "no_space_left_on_device_arena"],
+            CALENDAR
+                .select("7")
+                .into_iter()
+                .map(|p| p.name)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["supply_stacks"],
+            CALENDAR
+                .select("supply_stacks")
+                .into_iter()
+                .map(|p| p.name)
+                .collect::<Vec<_>>()
+        );
+        assert!(CALENDAR.select("monkey").is_empty());
+        assert!(CALENDAR.select("12").is_empty());
+    }
+
+    #[test]
+    fn test_answers() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let manifest = fs::read_to_string(root.join("answers.txt")).unwrap();
+        let manifest = answers::parse_manifest(&manifest).unwrap();
+
+        let failures = answers::check(PUZZLES, &INPUT_DIR, &manifest);
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_puzzles_sorted_and_unique() {
+        assert!(PUZZLES.windows(2).all(|w| w[0].day <= w[1].day));
+        for (i, a) in PUZZLES.iter().enumerate() {
+            assert!(PUZZLES[i + 1..].iter().all(|b| a.name != b.name));
+        }
+    }
+}
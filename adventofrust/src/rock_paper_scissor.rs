@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use std::io;
+use crate::{error::Error, solution::Solution};
 
 #[derive(Debug, Clone, Copy)]
 enum Play {
@@ -94,28 +94,77 @@ impl TryFrom<char> for Winnage {
     }
 }
 
-pub fn main() -> Result<(), String> {
-    let result = solutionate(io::stdin().lines())?;
-    println!("{}", result);
-
-    Ok(())
+impl Winnage {
+    /// Part 1 reads the second column as a play rather than an outcome: X, Y and Z stand for
+    /// rock, paper and scissor in the same order as loss, draw and win.
+    fn as_play(self) -> Play {
+        match self {
+            Winnage::Loss => Play::Rock,
+            Winnage::Draw => Play::Paper,
+            Winnage::Win => Play::Scissor,
+        }
+    }
 }
 
-fn solutionate<I: Iterator<Item = io::Result<String>>>(iter: I) -> Result<usize, String> {
-    iter.map(|x| {
-        x.map_err(|e| e.to_string()).and_then(|line| {
-            parse_round(line.as_str()).map_err(|_| format!("unable to parse line `{}`", line))
-        })
-    })
-    .sum::<Result<usize, String>>()
+/// Opponent play and second column of each round.
+pub struct StrategyGuide(Vec<(Play, Winnage)>);
+
+impl Solution for StrategyGuide {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(parse_round)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        Ok(self
+            .0
+            .iter()
+            .map(|&(opponent_play, column)| column.as_play().score_against(opponent_play))
+            .sum())
+    }
+
+    fn part2(&self) -> Result<usize, Error> {
+        Ok(self
+            .0
+            .iter()
+            .map(|&(opponent_play, goal)| {
+                opponent_play.deduce_play(goal).score_against(opponent_play)
+            })
+            .sum())
+    }
 }
 
-fn parse_round(s: &str) -> Result<usize, ()> {
-    let mut iter = s.chars();
-    let opponent_play = Play::try_from(iter.next().ok_or(())?)?;
+fn parse_round(s: &str) -> Result<(Play, Winnage), Error> {
+    let mut iter = s.char_indices().map(|(i, c)| (c, &s[i..i + c.len_utf8()]));
+    let missing = || Error::at(&s[s.len()..], "incomplete round");
+
+    let (c, snippet) = iter.next().ok_or_else(missing)?;
+    let opponent_play = Play::try_from(c).map_err(|_| Error::at(snippet, "expected A, B or C"))?;
     iter.next();
-    let goal = Winnage::try_from(iter.next().ok_or(())?)?;
-    let my_play = opponent_play.deduce_play(goal);
+    let (c, snippet) = iter.next().ok_or_else(missing)?;
+    let goal = Winnage::try_from(c).map_err(|_| Error::at(snippet, "expected X, Y or Z"))?;
 
-    Ok(my_play.score_against(opponent_play))
+    Ok((opponent_play, goal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "A Y
+B X
+C Z";
+
+    #[test]
+    fn test_solution() {
+        let guide = StrategyGuide::parse(INPUT).unwrap();
+        assert_eq!(Ok(15), guide.part1());
+        assert_eq!(Ok(12), guide.part2());
+    }
 }
@@ -2,10 +2,12 @@
 
 use std::{
     fmt::{Display, Write},
-    io,
     ops::IndexMut,
     str::FromStr,
 };
+
+use crate::{error::Error, solution::Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 struct Coordinate {
     x: i32,
@@ -57,42 +59,37 @@ struct Movement {
     distance: i32,
 }
 
-impl Movement {
-    fn from_str_opt(s: &str) -> Option<Self> {
-        let mut iter = s.chars();
-        let direction = match iter.next()? {
-            'R' => MovementDirection::Right,
-            'L' => MovementDirection::Left,
-            'D' => MovementDirection::Down,
-            'U' => MovementDirection::Up,
-            _ => return None,
-        };
+impl FromStr for Movement {
+    type Err = Error;
 
-        if !iter.next()?.is_whitespace() {
-            return None;
-        }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction_str, distance_str) = s
+            .split_once(' ')
+            .ok_or_else(|| Error::at(s, "expected a direction and a distance"))?;
+
+        let direction = match direction_str {
+            "R" => MovementDirection::Right,
+            "L" => MovementDirection::Left,
+            "D" => MovementDirection::Down,
+            "U" => MovementDirection::Up,
+            _ => return Err(Error::at(direction_str, "expected R, L, D or U")),
+        };
 
-        let distance = s[2..].parse().ok()?;
+        let distance = distance_str
+            .parse()
+            .map_err(|e| Error::at(distance_str, format!("invalid distance: {}", e)))?;
 
         if 0 < distance {
-            Some(Self {
+            Ok(Self {
                 direction,
                 distance,
             })
         } else {
-            None
+            Err(Error::at(distance_str, "distance must be positive"))
         }
     }
 }
 
-impl FromStr for Movement {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_str_opt(s).ok_or_else(|| format!("{} is not a valid movement", s))
-    }
-}
-
 impl Display for Movement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_char(match self.direction {
@@ -209,17 +206,38 @@ impl<const N: usize> Display for Simropelacrum<N> {
     }
 }
 
-pub fn main() -> Result<(), String> {
-    let mut sim = Simropelacrum::<10>::new();
-    for input in io::stdin().lines() {
-        let line = input.map_err(|e| e.to_string())?;
-        let movement: Movement = line.parse()?;
-        sim.moveify(movement);
+pub struct Motions(Vec<Movement>);
+
+impl Motions {
+    fn solutionate<const N: usize>(&self) -> usize {
+        let mut sim = Simropelacrum::<N>::new();
+        for &movement in &self.0 {
+            sim.moveify(movement);
+        }
+
+        sim.answerify()
+    }
+}
+
+impl Solution for Motions {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map(Self)
     }
 
-    println!("{}", sim.answerify());
+    fn part1(&self) -> Result<usize, Error> {
+        Ok(self.solutionate::<2>())
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<usize, Error> {
+        Ok(self.solutionate::<10>())
+    }
 }
 
 #[cfg(test)]
@@ -259,7 +277,7 @@ U 20";
             sim.moveify(m);
             println!("{}", m);
             println!("{}", sim);
-            println!("");
+            println!();
         }
 
         assert_eq!(36, sim.answerify());
@@ -1,12 +1,13 @@
 #![allow(dead_code)]
 use std::{
     array::IntoIter,
-    io,
     iter::zip,
     ops::{Index, IndexMut},
     str::FromStr,
 };
 
+use crate::{error::Error, solution::Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 struct Item(usize);
 
@@ -54,14 +55,13 @@ impl IntoIterator for Rucksack {
 }
 
 impl FromStr for Rucksack {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let indices = s
-            .chars()
-            .map(Item::try_from)
-            .collect::<Result<Vec<Item>, String>>()
-            .map_err(|e| format!("failed to parse rucksack {}: {}", s, e))?;
+            .char_indices()
+            .map(|(i, c)| Item::try_from(c).map_err(|e| Error::at(&s[i..i + c.len_utf8()], e)))
+            .collect::<Result<Vec<Item>, Error>>()?;
 
         let mut arr = [false; 52];
         for i in indices {
@@ -76,8 +76,8 @@ struct GroupPriorityIter<I> {
     source: I,
 }
 
-impl<I: Iterator<Item = Result<Rucksack, String>>> Iterator for GroupPriorityIter<I> {
-    type Item = Result<usize, String>;
+impl<I: Iterator<Item = Result<Rucksack, Error>>> Iterator for GroupPriorityIter<I> {
+    type Item = Result<usize, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let a = match self.source.next()? {
@@ -87,26 +87,26 @@ impl<I: Iterator<Item = Result<Rucksack, String>>> Iterator for GroupPriorityIte
         let b = match self.source.next() {
             Some(Ok(b)) => b,
             Some(Err(e)) => return Some(Err(e)),
-            None => return Some(Err("not enough input for a group".to_owned())),
+            None => return Some(Err(Error::new("not enough input for a group"))),
         };
         let c = match self.source.next() {
             Some(Ok(c)) => c,
             Some(Err(e)) => return Some(Err(e)),
-            None => return Some(Err("not enough input for a group".to_owned())),
+            None => return Some(Err(Error::new("not enough input for a group"))),
         };
 
         Some(
             zip(a, b)
                 .zip(c)
                 .position(|((a, b), c)| a && b && c)
-                .ok_or("no common item found among rucksacks".to_owned())
+                .ok_or_else(|| Error::new("no common item found among rucksacks"))
                 .map(|x| x + 1),
         )
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         fn f(n: usize) -> usize {
-            if n % 3 == 0 {
+            if n.is_multiple_of(3) {
                 n / 3
             } else {
                 n / 3 + 1
@@ -122,7 +122,7 @@ impl<I: Iterator<Item = Result<Rucksack, String>>> Iterator for GroupPriorityIte
     }
 }
 
-impl<I: Iterator<Item = Result<Rucksack, String>> + ExactSizeIterator> ExactSizeIterator
+impl<I: Iterator<Item = Result<Rucksack, Error>> + ExactSizeIterator> ExactSizeIterator
     for GroupPriorityIter<I>
 {
 }
@@ -137,18 +137,60 @@ impl<I: Iterator> GroupPriority<I> for I {
     }
 }
 
-pub fn main() -> Result<(), String> {
-    let iter = io::stdin().lines().map(|x| x.map_err(|e| e.to_string()));
-    let result = solutionate(iter)?;
-    println!("{}", result);
-
-    Ok(())
+impl Rucksack {
+    fn union(&self, other: &Self) -> Self {
+        let mut arr = self.0;
+        for (a, &b) in arr.iter_mut().zip(&other.0) {
+            *a |= b;
+        }
+        Self(arr)
+    }
 }
 
-fn solutionate<I: Iterator<Item = Result<String, String>>>(iter: I) -> Result<usize, String> {
-    iter.map(|x| x?.parse::<Rucksack>())
-        .group_priority()
-        .sum::<Result<usize, String>>()
+/// Contents of the two compartments of each rucksack.
+pub struct Rucksacks(Vec<(Rucksack, Rucksack)>);
+
+impl Solution for Rucksacks {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(|line| {
+                let half = line.len() / 2;
+                if !line.len().is_multiple_of(2) || !line.is_char_boundary(half) {
+                    return Err(Error::at(
+                        line,
+                        "rucksack cannot be split into two compartments",
+                    ));
+                }
+                let (a, b) = line.split_at(half);
+                Ok((a.parse()?, b.parse()?))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn part1(&self) -> Result<usize, Error> {
+        self.0
+            .iter()
+            .map(|(a, b)| {
+                zip(&a.0, &b.0)
+                    .position(|(&a, &b)| a && b)
+                    .ok_or_else(|| Error::new("no common item found among compartments"))
+                    .map(|x| x + 1)
+            })
+            .sum()
+    }
+
+    fn part2(&self) -> Result<usize, Error> {
+        self.0
+            .iter()
+            .map(|(a, b)| Ok(a.union(b)))
+            .group_priority()
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -172,6 +214,8 @@ CrZsJsPPZsGzwwsLwLmpwMDw";
 
     #[test]
     fn test_solution() {
-        assert_eq!(Ok(70), solutionate(INPUT.lines().map(|x| Ok(x.to_owned()))));
+        let rucksacks = Rucksacks::parse(INPUT).unwrap();
+        assert_eq!(Ok(157), rucksacks.part1());
+        assert_eq!(Ok(70), rucksacks.part2());
     }
 }
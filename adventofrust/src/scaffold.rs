@@ -0,0 +1,538 @@
+//! Generation of the boilerplate of a new day: the solution module with a test skeleton, its
+//! binary, its input directory and its bench, all registered with the rest of the crate.
+
+use std::{
+    fs,
+    io::{self, Write},
+    mem,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use crate::{error::Error, registry::Calendar};
+
+/// Where a crate keeps the files of each day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    /// Absolute path of the crate, usually `env!("CARGO_MANIFEST_DIR")`.
+    pub root: &'static str,
+    /// Name of the library, as used from binaries and benches.
+    pub lib: &'static str,
+    /// Whether each day also gets a binary in `src/bin`.
+    pub binaries: bool,
+}
+
+/// Name of the solution type of the module `name`, such as `MonkeyMap` for `monkey_map`.
+pub fn type_name(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn module_source(krate: &str, year: u16, day: u8, title: &str, ty: &str) -> String {
+    format!(
+        r#"//! Day {day}: {title}
+//! https://adventofcode.com/{year}/day/{day}
+
+#![allow(dead_code)]
+use {krate}::{{
+    error::Error,
+    solution::{{unsolved, Solution, Unsolved}},
+}};
+
+pub struct {ty}(Vec<String>);
+
+impl Solution for {ty} {{
+    type Answer1 = Unsolved;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, Error> {{
+        Ok(Self(input.lines().map(str::to_owned).collect()))
+    }}
+
+    fn part1(&self) -> Result<Unsolved, Error> {{
+        unsolved()
+    }}
+
+    fn part2(&self) -> Result<Unsolved, Error> {{
+        unsolved()
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    /// Example from the puzzle description.
+    const SAMPLE: &str = "";
+
+    #[test]
+    fn test_parse() {{
+        assert!({ty}::parse(SAMPLE).is_ok());
+    }}
+}}
+"#,
+        day = day,
+        title = title,
+        year = year,
+        krate = krate,
+        ty = ty,
+    )
+}
+
+/// Import of `items` from `krate`, one item per line.
+fn use_list(krate: &str, items: &[String]) -> String {
+    let items = items
+        .iter()
+        .map(|item| format!("    {},\n", item))
+        .collect::<String>();
+    format!("use {}::{{\n{}}};", krate, items)
+}
+
+/// Imports of `items` from `lib` and of `solution` from this crate.
+fn lib_imports(lib: &str, mut items: Vec<String>, solution: &str) -> String {
+    if lib == env!("CARGO_PKG_NAME") {
+        items.push(solution.to_owned());
+        return use_list(lib, &items);
+    }
+
+    format!(
+        "{}\nuse {}::{};",
+        use_list(lib, &items),
+        env!("CARGO_PKG_NAME"),
+        solution
+    )
+}
+
+/// `source` as formatted by `rustfmt`, or unchanged when `rustfmt` is missing or fails on it.
+fn rustfmt(source: String) -> String {
+    let format = || -> io::Result<Option<String>> {
+        let mut child = Command::new("rustfmt")
+            .args(["--edition", "2021"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(source.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        Ok(output
+            .status
+            .success()
+            .then(|| String::from_utf8(output.stdout).ok())
+            .flatten())
+    };
+    format().ok().flatten().unwrap_or(source)
+}
+
+fn binary_source(lib: &str, day: u8, name: &str, ty: &str) -> String {
+    let items = vec![
+        format!("{}::{}", name, ty),
+        "registry::INPUT_DIR".to_owned(),
+    ];
+    format!(
+        r#"use std::process::ExitCode;
+
+{imports}
+
+fn main() -> ExitCode {{
+    let path = INPUT_DIR.path({day}, "{name}");
+    solution::main::<{ty}>(path)
+}}
+"#,
+        imports = lib_imports(lib, items, "solution"),
+        name = name,
+        ty = ty,
+        day = day,
+    )
+}
+
+fn bench_source(lib: &str, day: u8, name: &str, ty: &str) -> String {
+    let items = vec![
+        format!("{}::{}", name, ty),
+        "registry::INPUT_DIR".to_owned(),
+    ];
+    let imports = lib_imports(lib, items, "solution::{self, Part, Solution}");
+
+    format!(
+        r#"use std::fs;
+
+{imports}
+use criterion::{{black_box, criterion_group, criterion_main, Criterion}};
+
+pub fn criterion_benchmark(c: &mut Criterion) {{
+    let path = INPUT_DIR.path({day}, "{name}");
+    let input = fs::read_to_string(path).expect("the input should be fetched first");
+    c.bench_function("{name} parse", |b| b.iter(|| {ty}::parse(black_box(&input))));
+
+    // Parts not solved yet would only measure their error.
+    let puzzle = {ty}::parse(&input).expect("the input should be valid");
+    if solution::is_solved::<{ty}>(Part::One) {{
+        c.bench_function("{name} part 1", |b| b.iter(|| black_box(&puzzle).part1()));
+    }}
+    if solution::is_solved::<{ty}>(Part::Two) {{
+        c.bench_function("{name} part 2", |b| b.iter(|| black_box(&puzzle).part2()));
+    }}
+}}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
+"#,
+        imports = imports,
+        day = day,
+        name = name,
+        ty = ty,
+    )
+}
+
+/// Declares the module `name` in `lib`, keeping the declarations sorted.
+fn add_module(lib: &str, name: &str) -> String {
+    let declaration = format!("pub mod {};", name);
+    let mut lines = lib.lines().collect::<Vec<_>>();
+
+    fn declared(line: &str) -> Option<&str> {
+        line.strip_prefix("pub mod ")?.strip_suffix(';')
+    }
+
+    let index = lines
+        .iter()
+        .position(|line| declared(line).is_some_and(|module| module > name))
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| declared(line).is_some())
+                .map(|i| i + 1)
+        })
+        .unwrap_or(lines.len());
+
+    lines.insert(index, &declaration);
+    lines.join("\n") + "\n"
+}
+
+/// Day of the `Puzzle::new` entry starting at `lines[i]`, whose arguments may start on the next
+/// line.
+fn entry_day(lines: &[&str], i: usize) -> Option<u8> {
+    let (_, args) = lines[i].split_once(">(")?;
+    let args = if args.trim().is_empty() {
+        lines.get(i + 1)?.trim()
+    } else {
+        args
+    };
+    args.split(',').next()?.trim().parse().ok()
+}
+
+/// Adds the module `name` to the `use crate::{...}` import of `registry`, keeping it sorted.
+fn add_import(registry: &str, name: &str) -> Result<String, Error> {
+    let start = registry
+        .find("use crate::{")
+        .ok_or_else(|| Error::new("registry has no `use crate::{...}` import"))?;
+    let end = start
+        + registry[start..]
+            .find("};")
+            .ok_or_else(|| Error::new("the `use crate::{...}` import is not terminated"))?;
+    let inner = &registry[start + "use crate::{".len()..end];
+
+    let mut items = Vec::new();
+    let (mut depth, mut item_start) = (0, 0);
+    for (i, ch) in inner.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(&inner[item_start..i]);
+                item_start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    items.push(&inner[item_start..]);
+    let mut items = items
+        .into_iter()
+        .map(|item| item.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|item| !item.is_empty())
+        .collect::<Vec<_>>();
+
+    let module = |item: &str| item.split("::").next().unwrap_or_default().to_owned();
+    if items.iter().any(|item| module(item) == name) {
+        return Err(Error::new(format!("{} is already imported", name)));
+    }
+    let index = items.partition_point(|item| module(item).as_str() < name);
+    items.insert(index, name.to_owned());
+
+    let import = use_list("crate", &items);
+
+    Ok(format!(
+        "{}{}{}",
+        &registry[..start],
+        import,
+        &registry[end + "};".len()..]
+    ))
+}
+
+/// Entry of `PUZZLES` for the solution `ty` of the module `name`.
+fn puzzle_entry(day: u8, name: &str, ty: &str, title: &str) -> String {
+    format!(
+        "    Puzzle::new::<{}::{}>({}, {:?}, {:?}),",
+        name, ty, day, name, title
+    )
+}
+
+/// Imports the module `name` in `registry` and adds its puzzle to `PUZZLES`, after the puzzles
+/// of the same day or earlier.
+fn add_puzzle(registry: &str, day: u8, name: &str, entry: &str) -> Result<String, Error> {
+    let registry = add_import(registry, name)?;
+
+    let mut lines = registry.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const PUZZLES: &[Puzzle] = &["))
+        .ok_or_else(|| Error::new("registry has no PUZZLES"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or_else(|| Error::new("PUZZLES is not terminated"))?;
+
+    let index = (start + 1..end)
+        .find(|&i| {
+            lines[i].trim_start().starts_with("Puzzle::new::<")
+                && entry_day(&lines, i).is_some_and(|d| d > day)
+        })
+        .unwrap_or(end);
+
+    lines.insert(index, entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds a criterion bench named `name` after the last one of `manifest`.
+fn add_bench(manifest: &str, name: &str) -> String {
+    let block = [
+        "[[bench]]",
+        &format!("name = \"{}\"", name),
+        "harness = false",
+    ];
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+
+    match lines.iter().rposition(|line| *line == "[[bench]]") {
+        Some(last) => match (last + 1..lines.len()).find(|&i| lines[i].starts_with('[')) {
+            Some(next) => {
+                lines.splice(next..next, block.into_iter().chain([""]));
+            }
+            None => lines.extend([""].into_iter().chain(block)),
+        },
+        None => lines.extend([""].into_iter().chain(block)),
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path)
+        .map_err(|e| Error::new(format!("failed to read {}: {}", path.display(), e)))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    let write_err =
+        |e: std::io::Error| Error::new(format!("failed to write {}: {}", path.display(), e));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(write_err)?;
+    }
+    fs::write(path, contents).map_err(write_err)
+}
+
+/// Creates the module `name` for `day` of `calendar`, with its binary when the crate has one per
+/// day, its input directory and its bench, then registers it in `lib.rs`, `registry.rs` and
+/// `Cargo.toml`. Every file is prepared, and formatted with `rustfmt` when it is installed,
+/// before any is written, so that the crate is left as it was if one of them can't be edited.
+/// Returns the paths created or modified.
+pub fn scaffold(
+    calendar: &Calendar,
+    day: u8,
+    name: &str,
+    title: &str,
+) -> Result<Vec<PathBuf>, Error> {
+    let valid_name = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid_name {
+        return Err(Error::new(format!("invalid module name: {}", name)));
+    }
+    if !(1..=25).contains(&day) {
+        return Err(Error::new(format!("invalid day: {}", day)));
+    }
+    if calendar.puzzles.iter().any(|p| p.name == name) {
+        return Err(Error::new(format!("{} is already registered", name)));
+    }
+
+    let layout = &calendar.layout;
+    let root = Path::new(layout.root);
+    let src = root.join("src");
+    let module = src.join(format!("{}.rs", name));
+    if module.exists() {
+        return Err(Error::new(format!("{} already exists", module.display())));
+    }
+
+    let ty = type_name(name);
+    let krate = if layout.lib == env!("CARGO_PKG_NAME") {
+        "crate"
+    } else {
+        "adventofrust"
+    };
+    let mut files = vec![(module, module_source(krate, calendar.year, day, title, &ty))];
+
+    if layout.binaries {
+        let binary = src.join("bin").join(format!("{}.rs", name));
+        files.push((binary, binary_source(layout.lib, day, name, &ty)));
+    }
+
+    let bench_name = format!("{}_bench", name);
+    let bench = root.join("benches").join(format!("{}.rs", bench_name));
+    files.push((bench, bench_source(layout.lib, day, name, &ty)));
+
+    let manifest = root.join("Cargo.toml");
+    let manifest_source = add_bench(&read(&manifest)?, &bench_name);
+    files.push((manifest, manifest_source));
+
+    let lib = src.join("lib.rs");
+    let lib_source = add_module(&read(&lib)?, name);
+    files.push((lib, lib_source));
+
+    let registry = src.join("registry.rs");
+    let entry = puzzle_entry(day, name, &ty, title);
+    let registry_source = add_puzzle(&read(&registry)?, day, name, &entry)?;
+    files.push((registry, registry_source));
+
+    for (path, contents) in &mut files {
+        if path.extension().is_some_and(|ext| ext == "rs") {
+            *contents = rustfmt(mem::take(contents));
+        }
+    }
+
+    let mut paths = Vec::new();
+    let input = calendar.input_dir.path(day, name);
+    if let Some(dir) = input.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| Error::new(format!("failed to create {}: {}", dir.display(), e)))?;
+        paths.push(dir.to_owned());
+    }
+
+    for (path, contents) in files {
+        write(&path, &contents)?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!("Day11", type_name("day11"));
+        assert_eq!("MonkeyMap", type_name("monkey_map"));
+    }
+
+    #[test]
+    fn test_add_module() {
+        let lib = "pub mod day1;\npub mod day10;\npub mod day2;\npub mod registry;\n";
+        assert_eq!(
+            "pub mod day1;\npub mod day10;\npub mod day11;\npub mod day2;\npub mod registry;\n",
+            add_module(lib, "day11")
+        );
+        assert_eq!(
+            "pub mod day1;\npub mod day10;\npub mod day2;\npub mod registry;\npub mod utils;\n",
+            add_module(lib, "utils")
+        );
+    }
+
+    #[test]
+    fn test_add_puzzle() {
+        let registry = r#"use crate::{day1, day3};
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day1::Trebuchet>(1, "day1", "Trebuchet?!"),
+    Puzzle::new::<day3::Schematic>(
+        3,
+        "day3",
+        "Gear Ratios",
+    ),
+];
+"#;
+        let entry = r#"    Puzzle::new::<day2::Day2>(2, "day2", "Cube Conundrum"),"#;
+
+        assert_eq!(
+            r#"use crate::{
+    day1,
+    day2,
+    day3,
+};
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day1::Trebuchet>(1, "day1", "Trebuchet?!"),
+    Puzzle::new::<day2::Day2>(2, "day2", "Cube Conundrum"),
+    Puzzle::new::<day3::Schematic>(
+        3,
+        "day3",
+        "Gear Ratios",
+    ),
+];
+"#,
+            add_puzzle(registry, 2, "day2", entry).unwrap()
+        );
+        assert!(add_puzzle("pub const PUZZLES: &[Puzzle] = &[\n];\n", 2, "day2", entry).is_err());
+        assert!(add_puzzle(registry, 1, "day1", entry).is_err());
+    }
+
+    #[test]
+    fn test_add_import() {
+        let registry =
+            "use crate::{\n    cathode_ray_tube,\n    error::Error,\n    full_of_hot_air,\n};\n";
+        assert_eq!(
+            "use crate::{\n    cathode_ray_tube,\n    day5,\n    error::Error,\n    full_of_hot_air,\n};\n",
+            add_import(registry, "day5").unwrap()
+        );
+        assert_eq!(
+            "use crate::{\n    day1,\n    day2,\n    solution::{self, Part},\n};\n",
+            add_import("use crate::{day1, solution::{self, Part}};\n", "day2").unwrap()
+        );
+        assert!(add_import(registry, "error").is_err());
+        assert!(add_import("use std::fs;\n", "day2").is_err());
+    }
+
+    #[test]
+    fn test_puzzle_entry() {
+        assert_eq!(
+            r#"    Puzzle::new::<day11::Day11>(11, "day11", "Cosmic Expansion"),"#,
+            puzzle_entry(11, "day11", "Day11", "Cosmic Expansion")
+        );
+    }
+
+    #[test]
+    fn test_rustfmt() {
+        // Left alone, whether or not `rustfmt` is installed.
+        assert_eq!("fn main( {", rustfmt("fn main( {".to_owned()));
+    }
+
+    #[test]
+    fn test_add_bench() {
+        let manifest = "[package]\nname = \"aoc\"\n\n[[bench]]\nname = \"day5_bench\"\nharness = false\n\n[dependencies]\n";
+        assert_eq!(
+            "[package]\nname = \"aoc\"\n\n[[bench]]\nname = \"day5_bench\"\nharness = false\n\n[[bench]]\nname = \"day6_bench\"\nharness = false\n\n[dependencies]\n",
+            add_bench(manifest, "day6_bench")
+        );
+        assert_eq!(
+            "[package]\n\n[[bench]]\nname = \"day6_bench\"\nharness = false\n",
+            add_bench("[package]\n", "day6_bench")
+        );
+    }
+}
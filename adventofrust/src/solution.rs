@@ -0,0 +1,166 @@
+//! Common interface of puzzle solutions: the input is parsed once, and each part computes its
+//! answer from the parsed value.
+
+use std::{any::TypeId, env, fmt::Display, path::PathBuf, process::ExitCode};
+
+use crate::{error::Error, input::Source};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const MEMBERS: [Self; 2] = [Self::One, Self::Two];
+}
+
+impl TryFrom<&str> for Part {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("invalid part: {} (expected 1 or 2)", value)),
+        }
+    }
+}
+
+impl From<Part> for usize {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// Answer type of a part that has not been solved. It has no values, so such a part can only
+/// ever return an error, and runners skip it altogether.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unsolved {}
+
+impl Display for Unsolved {
+    fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {}
+    }
+}
+
+/// Body of a part whose answer type is [`Unsolved`].
+pub fn unsolved() -> Result<Unsolved, Error> {
+    Err(Error::new("not solved yet"))
+}
+
+pub trait Solution: Sized {
+    type Answer1: Display + 'static;
+    type Answer2: Display + 'static;
+
+    fn parse(input: &str) -> Result<Self, Error>;
+
+    fn part1(&self) -> Result<Self::Answer1, Error>;
+
+    fn part2(&self) -> Result<Self::Answer2, Error>;
+}
+
+/// A [`Solution`] that can also show how it reached its answers.
+pub trait Trace: Solution {
+    fn trace(&self, part: Part) -> Result<String, Error>;
+}
+
+/// Whether `part` of `S` has a solution, i.e. its answer type isn't [`Unsolved`].
+pub fn is_solved<S: Solution>(part: Part) -> bool {
+    let answer = match part {
+        Part::One => TypeId::of::<S::Answer1>(),
+        Part::Two => TypeId::of::<S::Answer2>(),
+    };
+    answer != TypeId::of::<Unsolved>()
+}
+
+/// Object-safe view of a parsed [`Solution`], so that solutions of different types can be run
+/// through the same code.
+pub trait Solve {
+    fn solve(&self, part: Part) -> Result<String, Error>;
+}
+
+impl<S: Solution> Solve for S {
+    fn solve(&self, part: Part) -> Result<String, Error> {
+        match part {
+            Part::One => self.part1().map(|answer| answer.to_string()),
+            Part::Two => self.part2().map(|answer| answer.to_string()),
+        }
+    }
+}
+
+/// Entry point for binaries that solve a single puzzle: reads the input from the path given as
+/// the first argument, from stdin if that argument is `-`, or else from `default_input`, and
+/// prints the answer to every solved part.
+pub fn main<S: Solution>(default_input: PathBuf) -> ExitCode {
+    let source = match env::args().nth(1) {
+        Some(arg) => Source::from_arg(&arg),
+        None => Source::Path(default_input),
+    };
+
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let run = || -> Result<(), Error> {
+        let solution = S::parse(&input)?;
+        for part in Part::MEMBERS {
+            if is_solved::<S>(part) {
+                println!("{}", solution.solve(part)?);
+            }
+        }
+        Ok(())
+    };
+
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e.locate(&input));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines(usize);
+
+    impl Solution for Lines {
+        type Answer1 = usize;
+        type Answer2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self, Error> {
+            Ok(Self(input.lines().count()))
+        }
+
+        fn part1(&self) -> Result<usize, Error> {
+            Ok(self.0)
+        }
+
+        fn part2(&self) -> Result<Unsolved, Error> {
+            unsolved()
+        }
+    }
+
+    #[test]
+    fn test_is_solved() {
+        assert!(is_solved::<Lines>(Part::One));
+        assert!(!is_solved::<Lines>(Part::Two));
+    }
+
+    #[test]
+    fn test_solve() {
+        let solution = Lines::parse("a\nb\nc").unwrap();
+        assert_eq!(Ok("3".to_owned()), solution.solve(Part::One));
+        assert!(solution.solve(Part::Two).is_err());
+    }
+}
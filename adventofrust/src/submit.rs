@@ -0,0 +1,474 @@
+//! Submission of answers, with a local history of every attempt. The history is checked before
+//! posting, so that an answer already known to be wrong, or on the wrong side of a "too high" or
+//! "too low" hint, is never sent again.
+//!
+//! The history has one attempt per line, as the Unix time, the year, the day, the part, the
+//! verdict and the answer, separated by `|`.
+
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    client::{Client, Http},
+    error::Error,
+    solution::Part,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without any hint.
+    Wrong,
+    /// Submitted too soon after a previous wrong answer; the answer wasn't checked.
+    Wait(Duration),
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page returned after posting an answer.
+    pub fn from_response(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Some(Self::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Self::TooLow)
+            } else {
+                Some(Self::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Some(Self::Wait(
+                parse_wait(page).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+/// Reads the delay of "You have 4m 32s left to wait."
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, delay) = before.rsplit_once("You have ")?;
+
+    let mut secs = 0;
+    for field in delay.split_whitespace() {
+        let (value, unit) = field.split_at(field.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        secs += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => f.write_str("correct"),
+            Self::TooHigh => f.write_str("too-high"),
+            Self::TooLow => f.write_str("too-low"),
+            Self::Wrong => f.write_str("wrong"),
+            Self::Wait(delay) => write!(f, "wait-{}", delay.as_secs()),
+            Self::WrongLevel => f.write_str("wrong-level"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            "wrong-level" => Ok(Self::WrongLevel),
+            _ => s
+                .strip_prefix("wait-")
+                .and_then(|secs| secs.parse().ok())
+                .map(|secs| Self::Wait(Duration::from_secs(secs)))
+                .ok_or_else(|| format!("invalid verdict: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Unix time of the submission, in seconds.
+    pub time: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl TryFrom<&str> for Attempt {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let ferrstr = || format!("invalid attempt: {}", s);
+
+        let fields = s.splitn(6, '|').collect::<Vec<_>>();
+        let [time, year, day, part, verdict, answer] = fields[..] else {
+            return Err(ferrstr());
+        };
+
+        Ok(Self {
+            time: time.parse().map_err(|_| ferrstr())?,
+            year: year.parse().map_err(|_| ferrstr())?,
+            day: day.parse().map_err(|_| ferrstr())?,
+            part: Part::try_from(part)?,
+            verdict: verdict.parse()?,
+            answer: answer.to_owned(),
+        })
+    }
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.time,
+            self.year,
+            self.day,
+            usize::from(self.part),
+            self.verdict,
+            self.answer
+        )
+    }
+}
+
+/// Every attempt made so far, backed by a file to which new attempts are appended.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Reads the history at `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => {
+                return Err(Error::new(format!(
+                    "failed to read {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+
+        let attempts = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Attempt::try_from(line).map_err(|e| Error::at(line, e)))
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.locate(&text))?;
+
+        Ok(Self {
+            path: path.to_owned(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Decides whether `answer` is worth submitting at `now`, in Unix seconds. Returns the
+    /// verdict when it is already known to be correct, `None` when it should be submitted, and
+    /// an error explaining why it shouldn't otherwise.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> Result<Option<Verdict>, Error> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part);
+        let value = answer.parse::<i64>().ok();
+
+        for attempt in attempts {
+            let bound = attempt.answer.parse::<i64>().ok();
+            match attempt.verdict {
+                Verdict::Correct if attempt.answer == answer => return Ok(Some(Verdict::Correct)),
+                Verdict::Correct => {
+                    return Err(Error::new(format!(
+                        "already solved, the answer was {}",
+                        attempt.answer
+                    )))
+                }
+                Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong if attempt.answer == answer => {
+                    return Err(Error::new(format!("{} was already wrong", answer)))
+                }
+                Verdict::TooHigh if matches!((value, bound), (Some(v), Some(b)) if v >= b) => {
+                    return Err(Error::new(format!(
+                        "{} is too high, {} already was",
+                        answer, attempt.answer
+                    )))
+                }
+                Verdict::TooLow if matches!((value, bound), (Some(v), Some(b)) if v <= b) => {
+                    return Err(Error::new(format!(
+                        "{} is too low, {} already was",
+                        answer, attempt.answer
+                    )))
+                }
+                _ => {}
+            }
+        }
+
+        // The site makes the whole account wait, whatever puzzle was submitted.
+        let wait = self.attempts.iter().rev().find_map(|a| match a.verdict {
+            Verdict::Wait(delay) => Some(a.time + delay.as_secs()),
+            _ => None,
+        });
+        if let Some(until) = wait.filter(|&until| now < until) {
+            return Err(Error::new(format!(
+                "submitted too recently, wait {}s",
+                until - now
+            )));
+        }
+
+        Ok(None)
+    }
+
+    /// Appends `attempt` to the history and its file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), Error> {
+        let write_err = |e: std::io::Error| {
+            Error::new(format!("failed to write {}: {}", self.path.display(), e))
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(write_err)?;
+        writeln!(file, "{}", attempt).map_err(write_err)?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Submits `answer` for `part` of `day` of `year`, unless `history` shows that it's pointless,
+/// and records the verdict.
+pub fn submit<H: Http>(
+    client: &Client<H>,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, Error> {
+    if answer.is_empty() || answer.contains('\n') {
+        return Err(Error::new(format!("invalid answer: {:?}", answer)));
+    }
+
+    let now = unix_now();
+    if let Some(verdict) = history.check(year, day, part, answer, now)? {
+        return Ok(verdict);
+    }
+
+    let level = usize::from(part).to_string();
+    let response = client.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    if response.status != 200 {
+        return Err(Error::new(format!(
+            "unexpected status {} when submitting {} day {} part {}",
+            response.status, year, day, level
+        )));
+    }
+    let verdict = Verdict::from_response(&response.body)
+        .ok_or_else(|| Error::new("unrecognized response to the submission"))?;
+
+    history.record(Attempt {
+        time: now,
+        year,
+        day,
+        part,
+        verdict,
+        answer: answer.to_owned(),
+    })?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+    use crate::client::{stand_in::serve, Ureq};
+
+    fn attempt(time: u64, part: Part, verdict: Verdict, answer: &str) -> Attempt {
+        Attempt {
+            time,
+            year: 2022,
+            day: 1,
+            part,
+            verdict,
+            answer: answer.to_owned(),
+        }
+    }
+
+    fn history(attempts: Vec<Attempt>) -> History {
+        History {
+            path: PathBuf::new(),
+            attempts,
+        }
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        assert_eq!(
+            Some(Verdict::Correct),
+            Verdict::from_response("<p>That's the right answer! You are one gold star closer.</p>")
+        );
+        assert_eq!(
+            Some(Verdict::TooHigh),
+            Verdict::from_response("<p>That's not the right answer; your answer is too high.</p>")
+        );
+        assert_eq!(
+            Some(Verdict::TooLow),
+            Verdict::from_response("<p>That's not the right answer; your answer is too low.</p>")
+        );
+        assert_eq!(
+            Some(Verdict::Wrong),
+            Verdict::from_response("<p>That's not the right answer. If you're stuck...</p>")
+        );
+        assert_eq!(
+            Some(Verdict::Wait(Duration::from_secs(272))),
+            Verdict::from_response(
+                "<p>You gave an answer too recently. You have 4m 32s left to wait.</p>"
+            )
+        );
+        assert_eq!(
+            Some(Verdict::WrongLevel),
+            Verdict::from_response("<p>You don't seem to be solving the right level.</p>")
+        );
+        assert_eq!(None, Verdict::from_response("<p>Something else</p>"));
+    }
+
+    #[test]
+    fn test_attempt_round_trip() {
+        for verdict in [Verdict::TooLow, Verdict::Wait(Duration::from_secs(30))] {
+            let attempt = attempt(1670000000, Part::Two, verdict, "a|b");
+            assert_eq!(
+                Ok(attempt.clone()),
+                Attempt::try_from(attempt.to_string().as_str())
+            );
+        }
+        assert!(Attempt::try_from("1670000000|2022|1|3|correct|42").is_err());
+        assert!(Attempt::try_from("1670000000|2022|1|1|correct").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let history = history(vec![
+            attempt(0, Part::One, Verdict::TooHigh, "100"),
+            attempt(10, Part::One, Verdict::TooLow, "10"),
+            attempt(20, Part::One, Verdict::Wrong, "abc"),
+            attempt(30, Part::One, Verdict::Wait(Duration::from_secs(60)), "50"),
+            attempt(40, Part::Two, Verdict::Correct, "7"),
+        ]);
+
+        assert!(history.check(2022, 1, Part::One, "100", 100).is_err());
+        assert!(history.check(2022, 1, Part::One, "150", 100).is_err());
+        assert!(history.check(2022, 1, Part::One, "5", 100).is_err());
+        assert!(history.check(2022, 1, Part::One, "abc", 100).is_err());
+        assert!(history.check(2022, 1, Part::One, "50", 60).is_err());
+        assert!(history.check(2022, 2, Part::One, "50", 60).is_err());
+        assert!(history.check(2023, 1, Part::Two, "50", 89).is_err());
+        assert_eq!(Ok(None), history.check(2022, 1, Part::One, "50", 100));
+        assert_eq!(Ok(None), history.check(2023, 1, Part::One, "100", 100));
+
+        assert_eq!(
+            Ok(Some(Verdict::Correct)),
+            history.check(2022, 1, Part::Two, "7", 100)
+        );
+        assert!(history.check(2022, 1, Part::Two, "8", 100).is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = serve(vec![
+            (
+                200,
+                "<p>That's not the right answer; your answer is too low.</p>",
+            ),
+            (200, "<p>That's the right answer!</p>"),
+            (
+                200,
+                "<p>That's not the right answer; your answer is too high.</p>",
+            ),
+        ]);
+        let client =
+            Client::new(Ureq::default(), &server.url, "abc123").with_min_interval(Duration::ZERO);
+        let path = env::temp_dir().join(format!("adventofrust-history-{}.log", process::id()));
+        let _ = fs::remove_file(&path);
+        let mut history = History::load(&path).unwrap();
+
+        assert_eq!(
+            Ok(Verdict::TooLow),
+            submit(&client, &mut history, 2022, 1, Part::One, "10")
+        );
+        assert!(submit(&client, &mut history, 2022, 1, Part::One, "9").is_err());
+        assert_eq!(
+            Ok(Verdict::Correct),
+            submit(&client, &mut history, 2022, 1, Part::One, "11")
+        );
+        assert_eq!(
+            Ok(Verdict::Correct),
+            submit(&client, &mut history, 2022, 1, Part::One, "11")
+        );
+        assert_eq!(
+            Ok(Verdict::TooHigh),
+            submit(&client, &mut history, 2022, 1, Part::Two, "a|b")
+        );
+
+        let requests = server.requests();
+        assert_eq!(3, requests.len());
+        assert!(requests[0].starts_with("POST /2022/day/1/answer "));
+        assert!(requests[0].ends_with("level=1&answer=10"));
+
+        let reloaded = History::load(&path).unwrap();
+        assert_eq!(history.attempts(), reloaded.attempts());
+        assert_eq!(3, reloaded.attempts().len());
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -1,7 +1,9 @@
 #![allow(dead_code)]
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::{cmp::max, fmt::Display, io, ops::IndexMut, str::FromStr};
+use std::{cmp::max, fmt::Display, ops::IndexMut, str::FromStr};
+
+use crate::{error::Error, solution::Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Item(char);
@@ -23,10 +25,10 @@ impl Item {
 }
 
 impl FromStr for Item {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Item::from_str_opt(s).ok_or_else(|| format!("{} is not a valid `Item`", s))
+        Item::from_str_opt(s).ok_or_else(|| Error::at(s, "expected a crate like `[A]`"))
     }
 }
 
@@ -54,37 +56,46 @@ impl Display for Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        fn aux(s: &str) -> Option<Instruction> {
-            lazy_static! {
-                static ref RE: Regex = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
-            }
-
-            let captures = RE.captures(s)?;
-            let quantity = captures.get(1)?.as_str().parse().ok()?;
-            let from = captures
-                .get(2)?
-                .as_str()
-                .parse::<usize>()
-                .ok()?
-                .checked_sub(1)?;
-            let to = captures
-                .get(3)?
-                .as_str()
-                .parse::<usize>()
-                .ok()?
-                .checked_sub(1)?;
-
-            Some(Instruction { quantity, from, to })
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
         }
 
-        aux(s).ok_or_else(|| format!("{} is not a valid instruction", s))
+        let captures = RE
+            .captures(s)
+            .ok_or_else(|| Error::at(s, "expected `move N from A to B`"))?;
+        let number = |i: usize| {
+            let capture = captures.get(i).map_or(s, |m| m.as_str());
+            capture
+                .parse::<usize>()
+                .map_err(|e| Error::at(capture, e.to_string()))
+                .map(|n| (n, capture))
+        };
+        let stack = |i: usize| {
+            let (n, capture) = number(i)?;
+            n.checked_sub(1)
+                .ok_or_else(|| Error::at(capture, "stacks are numbered from 1"))
+        };
+
+        Ok(Instruction {
+            quantity: number(1)?.0,
+            from: stack(2)?,
+            to: stack(3)?,
+        })
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Crane {
+    /// Moves crates one at a time, reversing their order.
+    CrateMover9000,
+    /// Moves several crates at once, keeping their order.
+    CrateMover9001,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct Staging(Vec<Vec<Item>>);
 
 impl Staging {
@@ -112,7 +123,11 @@ impl Staging {
         }
     }
 
-    fn process_instruction(&mut self, instruction: Instruction) -> Result<(), String> {
+    fn process_instruction(
+        &mut self,
+        instruction: Instruction,
+        crane: Crane,
+    ) -> Result<(), String> {
         let Instruction { quantity, from, to } = instruction;
 
         let max_index = max(from, to);
@@ -134,7 +149,10 @@ impl Staging {
             )
         })?;
 
-        let stuff_to_move: Vec<Item> = from_stack.drain(partition_index..).collect();
+        let mut stuff_to_move: Vec<Item> = from_stack.drain(partition_index..).collect();
+        if crane == Crane::CrateMover9000 {
+            stuff_to_move.reverse();
+        }
         self.0[to].extend(stuff_to_move);
 
         Ok(())
@@ -156,33 +174,53 @@ impl Staging {
     }
 }
 
-pub fn main() -> Result<(), String> {
-    let iter = io::stdin().lines().map(|x| x.map_err(|e| e.to_string()));
-    let result = solutionate(iter)?;
-    println!("{}", result);
+pub struct Procedure {
+    staging: Staging,
+    instructions: Vec<Instruction>,
+}
 
-    Ok(())
+impl Procedure {
+    fn rearrange(&self, crane: Crane) -> Result<String, Error> {
+        let mut staging = self.staging.clone();
+        for &instruction in &self.instructions {
+            staging.process_instruction(instruction, crane)?;
+        }
+        Ok(staging.get_answer())
+    }
 }
 
-fn solutionate(mut iter: impl Iterator<Item = Result<String, String>>) -> Result<String, String> {
-    let mut staging = Staging::new();
+impl Solution for Procedure {
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        let mut lines = input.lines();
+        let mut staging = Staging::new();
 
-    for line in iter.by_ref() {
-        let line = line?;
-        if line.is_empty() {
-            break;
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            staging.process_line(line);
         }
-        staging.process_line(&line);
-    }
 
-    staging.finalize_stacks();
+        staging.finalize_stacks();
+
+        let instructions = lines.map(str::parse).collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            staging,
+            instructions,
+        })
+    }
 
-    for line in iter {
-        let instruction: Instruction = line?.parse()?;
-        staging.process_instruction(instruction)?;
+    fn part1(&self) -> Result<String, Error> {
+        self.rearrange(Crane::CrateMover9000)
     }
 
-    Ok(staging.get_answer())
+    fn part2(&self) -> Result<String, Error> {
+        self.rearrange(Crane::CrateMover9001)
+    }
 }
 
 #[cfg(test)]
@@ -201,10 +239,9 @@ move 1 from 1 to 2";
 
     #[test]
     fn test_solution() {
-        assert_eq!(
-            Ok("MCD".to_owned()),
-            solutionate(INPUT.lines().map(|x| Ok(x.to_owned())))
-        );
+        let procedure = Procedure::parse(INPUT).unwrap();
+        assert_eq!(Ok("CMZ".to_owned()), procedure.part1());
+        assert_eq!(Ok("MCD".to_owned()), procedure.part2());
     }
 
     #[test]
@@ -235,11 +272,14 @@ move 1 from 1 to 2";
             vec![Item('P')],
         ]);
         assert!(staging
-            .process_instruction(Instruction {
-                quantity: 1,
-                from: 1,
-                to: 0,
-            })
+            .process_instruction(
+                Instruction {
+                    quantity: 1,
+                    from: 1,
+                    to: 0,
+                },
+                Crane::CrateMover9001
+            )
             .is_ok());
         assert_eq!(
             Staging(vec![
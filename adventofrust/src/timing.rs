@@ -0,0 +1,165 @@
+//! Timing of the parse and of each part of the registered puzzles, to see which days are slow
+//! and notice when one regresses.
+
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use crate::{error::Error, registry::Puzzle, solution::Part};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub puzzle: &'static str,
+    pub parse: Duration,
+    /// Time of each part, or `None` for an unsolved one.
+    pub parts: [Option<Duration>; 2],
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().flatten().sum::<Duration>()
+    }
+}
+
+/// Times `puzzle` on `input`, keeping the fastest of `runs` runs of each step.
+pub fn time(puzzle: &Puzzle, input: &str, runs: usize) -> Result<Timing, Error> {
+    let mut timing = Timing {
+        day: puzzle.day,
+        puzzle: puzzle.name,
+        parse: Duration::MAX,
+        parts: Part::MEMBERS.map(|part| puzzle.is_solved(part).then_some(Duration::MAX)),
+    };
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let solution = puzzle.parse(input)?;
+        timing.parse = timing.parse.min(start.elapsed());
+
+        for (part, best) in Part::MEMBERS.into_iter().zip(&mut timing.parts) {
+            if let Some(best) = best {
+                let start = Instant::now();
+                solution.solve(part).map_err(|e| e.in_puzzle(puzzle.name))?;
+                *best = (*best).min(start.elapsed());
+            }
+        }
+    }
+
+    Ok(timing)
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+/// Table of `timings` in milliseconds, slowest first.
+pub fn table(timings: &[Timing]) -> String {
+    let mut timings = timings.iter().collect::<Vec<_>>();
+    timings.sort_by_key(|timing| std::cmp::Reverse(timing.total()));
+
+    let mut out = format!(
+        "{:>3}  {:<32} {:>12} {:>12} {:>12} {:>12}\n",
+        "day", "puzzle", "parse (ms)", "part 1 (ms)", "part 2 (ms)", "total (ms)"
+    );
+    for timing in timings {
+        let [part1, part2] = timing.parts.map(|part| part.map_or("-".to_owned(), millis));
+        let _ = writeln!(
+            out,
+            "{:>3}  {:<32} {:>12} {:>12} {:>12} {:>12}",
+            timing.day,
+            timing.puzzle,
+            millis(timing.parse),
+            part1,
+            part2,
+            millis(timing.total())
+        );
+    }
+
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// `timings` and `failures` as a JSON array with one object per puzzle, in the given order.
+/// Durations are in nanoseconds, and `null` for unsolved parts.
+pub fn json(timings: &[Timing], failures: &[(&Puzzle, Error)]) -> String {
+    let mut objects = timings
+        .iter()
+        .map(|timing| {
+            let [part1, part2] = timing
+                .parts
+                .map(|part| part.map_or("null".to_owned(), |d| d.as_nanos().to_string()));
+            format!(
+                "{{\"day\":{},\"puzzle\":{},\"parse_ns\":{},\"part1_ns\":{},\"part2_ns\":{},\"total_ns\":{}}}",
+                timing.day,
+                json_string(timing.puzzle),
+                timing.parse.as_nanos(),
+                part1,
+                part2,
+                timing.total().as_nanos()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    objects.extend(failures.iter().map(|(puzzle, e)| {
+        format!(
+            "{{\"day\":{},\"puzzle\":{},\"error\":{}}}",
+            puzzle.day,
+            json_string(puzzle.name),
+            json_string(&e.to_string())
+        )
+    }));
+
+    format!("[{}]", objects.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(puzzle: &'static str, parse: u64, part2: Option<u64>) -> Timing {
+        Timing {
+            day: 1,
+            puzzle,
+            parse: Duration::from_micros(parse),
+            parts: [
+                Some(Duration::from_micros(1)),
+                part2.map(Duration::from_micros),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_table_sorted() {
+        let table = table(&[timing("fast", 1, None), timing("slow", 1, Some(2000))]);
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(3, lines.len());
+        assert!(lines[1].contains("slow") && lines[1].ends_with("2.002"));
+        assert!(lines[2].contains("fast") && lines[2].contains(" - "));
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            r#"[{"day":1,"puzzle":"a\"b","parse_ns":1000,"part1_ns":1000,"part2_ns":null,"total_ns":2000}]"#,
+            json(&[timing("a\"b", 1, None)], &[])
+        );
+    }
+}
@@ -1,10 +1,11 @@
 #![allow(dead_code)]
 use std::{
     fmt::{Display, Write},
-    io::{self, Read},
     str::FromStr,
 };
 
+use crate::{error::Error, solution::Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Tile {
     height: u8,
@@ -26,7 +27,7 @@ impl TryFrom<char> for Tile {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Board(Vec<Vec<Tile>>);
+pub struct Board(Vec<Vec<Tile>>);
 
 impl Board {
     fn height(&self) -> usize {
@@ -37,6 +38,30 @@ impl Board {
         self.0.first().map(|x| x.len()).unwrap_or(0)
     }
 
+    fn count_visible(&self) -> usize {
+        let board_width = self.width();
+        let board_height = self.height();
+
+        let mut count = 0;
+
+        for x in 0..board_width {
+            for y in 0..board_height {
+                let tree_height = self.0[y][x].height;
+
+                let visible = (0..y).all(|i| self.0[i][x].height < tree_height)
+                    || (0..x).all(|i| self.0[y][i].height < tree_height)
+                    || (y + 1..board_height).all(|i| self.0[i][x].height < tree_height)
+                    || (x + 1..board_width).all(|i| self.0[y][i].height < tree_height);
+
+                if visible {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
     fn solutionate(&mut self) -> usize {
         let board_width = self.width();
         let board_height = self.height();
@@ -79,18 +104,20 @@ impl Board {
 }
 
 impl FromStr for Board {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let map_line = |line: &str| {
-            line.chars()
-                .map(Tile::try_from)
-                .collect::<Result<_, ()>>()
-                .map_err(|_| "input contains invalid character")
+            line.char_indices()
+                .map(|(i, c)| {
+                    Tile::try_from(c)
+                        .map_err(|_| Error::at(&line[i..i + c.len_utf8()], "expected a digit"))
+                })
+                .collect::<Result<_, _>>()
         };
 
         if s.is_empty() {
-            return Err("input is empty");
+            return Err(Error::new("input is empty"));
         }
 
         let mut width = None;
@@ -101,7 +128,7 @@ impl FromStr for Board {
                     if w == line.len() {
                         map_line(line)
                     } else {
-                        Err("input is not a grid.")
+                        Err(Error::at(line, format!("expected {} trees", w)))
                     }
                 } else {
                     width = Some(line.len());
@@ -142,16 +169,21 @@ impl Display for Board {
     }
 }
 
-pub fn main() -> Result<(), String> {
-    let mut s = String::new();
-    io::stdin()
-        .read_to_string(&mut s)
-        .map_err(|e| e.to_string())?;
+impl Solution for Board {
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let mut board: Board = s.parse()?;
-    println!("{}", board.solutionate());
+    fn parse(input: &str) -> Result<Self, Error> {
+        input.parse()
+    }
 
-    Ok(())
+    fn part1(&self) -> Result<usize, Error> {
+        Ok(self.count_visible())
+    }
+
+    fn part2(&self) -> Result<usize, Error> {
+        Ok(self.clone().solutionate())
+    }
 }
 
 #[cfg(test)]
@@ -211,6 +243,7 @@ mod tests {
     #[test]
     fn test_solution() {
         let mut board: Board = INPUT.parse().unwrap();
+        assert_eq!(21, board.count_visible());
         assert_eq!(8, board.solutionate());
     }
 }
@@ -1,20 +1,31 @@
 #![allow(dead_code)]
-use std::io;
 
-pub fn main() -> Result<(), String> {
-    let mut line = String::new();
-    io::stdin()
-        .read_line(&mut line)
-        .map_err(|e| e.to_string())?;
+use crate::{
+    error::{self, Error},
+    solution::Solution,
+};
 
-    if !line.is_ascii() {
-        return Err("non-ascii characters are not supported.".to_owned());
+pub struct Datastream(String);
+
+impl Solution for Datastream {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        let line = input.lines().next().unwrap_or_default();
+
+        error::ensure_ascii(line)?;
+
+        Ok(Self(line.to_owned()))
     }
 
-    let result = start_of_message(&line).ok_or("marker not found")?;
-    println!("{}", result);
+    fn part1(&self) -> Result<usize, Error> {
+        start_of_packet(&self.0).ok_or_else(|| Error::new("marker not found"))
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<usize, Error> {
+        start_of_message(&self.0).ok_or_else(|| Error::new("marker not found"))
+    }
 }
 
 fn start_of_packet(s: &str) -> Option<usize> {
@@ -24,7 +35,9 @@ fn start_of_packet(s: &str) -> Option<usize> {
         .windows(WINDOW_SIZE)
         .enumerate()
         .find_map(|(i, window)| {
-            let &[a, b, c, d] = window else { unreachable!() };
+            let &[a, b, c, d] = window else {
+                unreachable!()
+            };
             if a == b || a == c || a == d || b == c || b == d || c == d {
                 None
             } else {
@@ -52,3 +65,15 @@ fn start_of_message(s: &str) -> Option<usize> {
         })
         .map(|x| x + WINDOW_SIZE)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solution() {
+        let datastream = Datastream::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+        assert_eq!(Ok(7), datastream.part1());
+        assert_eq!(Ok(19), datastream.part2());
+    }
+}
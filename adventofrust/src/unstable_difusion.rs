@@ -6,12 +6,13 @@
 use std::{
     collections::VecDeque,
     fmt::{Display, Write},
-    fs,
     iter::once,
     ops::{Index, IndexMut},
     str::FromStr,
 };
 
+use crate::{error::Error, solution::Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Direction {
     North,
@@ -121,8 +122,8 @@ impl From<Tile> for char {
     }
 }
 
-#[derive(Debug)]
-struct Board {
+#[derive(Debug, Clone)]
+pub struct Board {
     board: VecDeque<VecDeque<Tile>>,
     turn: usize,
 }
@@ -313,22 +314,28 @@ impl IndexMut<Coordinate> for Board {
 }
 
 impl FromStr for Board {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lines = s.lines();
-        let input_width = lines.next().ok_or(())?.len();
+        let input_width = lines
+            .next()
+            .ok_or_else(|| Error::at(s, "the grove is empty"))?
+            .len();
         let input_height = lines.count() + 1;
 
         let iter = s.lines().map(|line| {
             if line.len() != input_width {
-                return Err(());
+                return Err(Error::at(line, format!("expected {} tiles", input_width)));
             }
 
-            once('.')
-                .chain(line.chars())
-                .chain(once('.'))
-                .map(Tile::try_from)
+            let tiles = line.char_indices().map(|(i, ch)| {
+                Tile::try_from(ch)
+                    .map_err(|_| Error::at(&line[i..i + ch.len_utf8()], "expected '.' or '#'"))
+            });
+            once(Ok(Tile::Empty))
+                .chain(tiles)
+                .chain(once(Ok(Tile::Empty)))
                 .collect()
         });
 
@@ -357,17 +364,25 @@ impl Display for Board {
     }
 }
 
-const INPUT_FILENAME: &str = "input/unstable_difusion.txt";
+impl Solution for Board {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        input.parse()
+    }
 
-pub fn main() -> Result<(), String> {
-    let input = fs::read_to_string(INPUT_FILENAME).map_err(|e| e.to_string())?;
-    let mut board: Board = input.parse().map_err(|_| "Parse error.")?;
-    board.run();
-    println!("{}", board);
-    println!("{}", board.count_empty_tiles());
-    println!("{}", board.turn);
+    fn part1(&self) -> Result<usize, Error> {
+        let mut board = self.clone();
+        board.iterate(10);
+        Ok(board.count_empty_tiles())
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<usize, Error> {
+        let mut board = self.clone();
+        board.run();
+        Ok(board.turn)
+    }
 }
 
 #[cfg(test)]
@@ -15,7 +15,7 @@ pub struct LinkedNodeEntry<'a, T> {
 impl<T> Copy for LinkedNodeEntry<'_, T> {}
 impl<T> Clone for LinkedNodeEntry<'_, T> {
     fn clone(&self) -> Self {
-        Self { ..*self }
+        *self
     }
 }
 
@@ -28,7 +28,7 @@ impl<T> LinkedNodeEntry<'_, T> {
         })
     }
 
-    pub fn iter(&self) -> LinkedListIter<T> {
+    pub fn iter(&self) -> LinkedListIter<'_, T> {
         self.into_iter()
     }
 }
@@ -184,9 +184,9 @@ impl<'a, T> IntoIterator for LinkedListEntry<'a, T> {
 pub struct LinkedListIter<'a, T>(Option<LinkedNodeEntry<'a, T>>);
 
 impl<T> Copy for LinkedListIter<'_, T> {}
-impl<'a, T> Clone for LinkedListIter<'a, T> {
+impl<T> Clone for LinkedListIter<'_, T> {
     fn clone(&self) -> Self {
-        Self(self.0)
+        *self
     }
 }
 
@@ -221,7 +221,7 @@ impl<T> ArenaLinkedList<T> {
         }
     }
 
-    pub fn add_list(&mut self) -> LinkedListEntry<T> {
+    pub fn add_list(&mut self) -> LinkedListEntry<'_, T> {
         let i = self.heads.len();
         self.heads.push(None);
         LinkedListEntry {
@@ -230,7 +230,7 @@ impl<T> ArenaLinkedList<T> {
         }
     }
 
-    pub fn insert_list(&mut self, index: usize) -> LinkedListEntry<T> {
+    pub fn insert_list(&mut self, index: usize) -> LinkedListEntry<'_, T> {
         self.heads.push(None);
         LinkedListEntry {
             source: self,
@@ -238,7 +238,7 @@ impl<T> ArenaLinkedList<T> {
         }
     }
 
-    pub fn get_list(&self, index: usize) -> LinkedListIter<T> {
+    pub fn get_list(&self, index: usize) -> LinkedListIter<'_, T> {
         let node_entry = self
             .heads
             .get(index)
@@ -250,7 +250,7 @@ impl<T> ArenaLinkedList<T> {
         LinkedListIter(node_entry)
     }
 
-    pub fn get_list_mut(&mut self, index: usize) -> LinkedListEntry<T> {
+    pub fn get_list_mut(&mut self, index: usize) -> LinkedListEntry<'_, T> {
         LinkedListEntry {
             source: self,
             head_index: index,
@@ -27,10 +27,7 @@ pub struct TreeNodeEntry<'a, T> {
 impl<T> Copy for TreeNodeEntry<'_, T> {}
 impl<T> Clone for TreeNodeEntry<'_, T> {
     fn clone(&self) -> Self {
-        Self {
-            source: self.source,
-            node: self.node,
-        }
+        *self
     }
 }
 
@@ -78,9 +75,9 @@ pub struct NodeChildren<'a, T> {
 }
 
 impl<T> Copy for NodeChildren<'_, T> {}
-impl<'a, T> Clone for NodeChildren<'a, T> {
+impl<T> Clone for NodeChildren<'_, T> {
     fn clone(&self) -> Self {
-        Self { ..*self }
+        *self
     }
 }
 
@@ -115,7 +112,7 @@ impl<T> TreeNodeEntryMut<'_, T> {
         self.index = self.source.nodes[self.index].parent_index;
     }
 
-    pub fn get_children(&self) -> NodeChildren<T> {
+    pub fn get_children(&self) -> NodeChildren<'_, T> {
         NodeChildren {
             source: self.source,
             index_iter: self
@@ -1,4 +1,4 @@
-trait IsNoneOr<T>: Copy {
+pub trait IsNoneOr<T>: Copy {
     fn is_none_or(self, f: impl FnOnce(T) -> bool) -> bool;
 }
 
@@ -1,3 +1,5 @@
 pub mod arena_linked_list;
 pub mod arena_tree;
 pub mod is_none_or;
+pub mod paragraphs;
+pub mod search;
@@ -0,0 +1,44 @@
+//! Splitting of inputs made of several sections separated by blank lines, whatever their line
+//! endings.
+
+/// The paragraphs of `input`, i.e. its runs of non-empty lines, without the line ending of their
+/// last line. Lines may end with `\n` or `\r\n`. The paragraphs are slices of `input`, so errors
+/// found in them can still be located in `input`.
+pub fn paragraphs(input: &str) -> Vec<&str> {
+    let mut paragraphs = Vec::new();
+    let mut start = None;
+    let (mut offset, mut end) = (0, 0);
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.is_empty() {
+            if let Some(start) = start.take() {
+                paragraphs.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        paragraphs.push(&input[start..end]);
+    }
+
+    paragraphs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paragraphs() {
+        assert_eq!(vec!["a\nb", "c"], paragraphs("a\nb\n\nc\n"));
+        assert_eq!(
+            vec!["  a\r\nb", "c"],
+            paragraphs("\r\n  a\r\nb\r\n\r\n\r\nc")
+        );
+        assert!(paragraphs("\n\r\n").is_empty());
+    }
+}
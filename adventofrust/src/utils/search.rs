@@ -0,0 +1,312 @@
+//! Shortest paths over any state type, given the successors of each state: breadth-first search
+//! for unweighted moves, Dijkstra for weighted ones, and A* when a heuristic is known.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Outcome of a search: the distance to every state settled, the state each one was reached
+/// from on a shortest path, and the goal if one was found. States are only settled up to the
+/// goal, or until the search runs out of states when there is none.
+///
+/// A state is settled once its distance is final: as soon as it is reached by a breadth-first
+/// search, but only once it is expanded by Dijkstra or A*, so states still waiting in their
+/// queue when the goal is found are left out.
+#[derive(Debug, Clone)]
+pub struct Paths<S, C> {
+    distances: HashMap<S, C>,
+    parents: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Paths<S, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The first goal reached, which is the closest one.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// Distance from the nearest start to the goal.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// Path from the nearest start to the goal, both included.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Distance from the nearest start to `state`, if `state` was settled.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// Distances of all the settled states.
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// A shortest path from a start to `state`, both included, if `state` was settled.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from `starts`, where each move to one of `successors` costs 1, stopping
+/// at the first state for which `is_goal` is true.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !paths.distances.contains_key(&start) {
+            paths.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            paths.goal = Some(state);
+            break;
+        }
+
+        let distance = paths.distances[&state] + 1;
+        for next in successors(&state) {
+            if !paths.distances.contains_key(&next) {
+                paths.distances.insert(next.clone(), distance);
+                paths.parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's search from `starts`, where `successors` gives the cost of each move, stopping at
+/// the first state for which `is_goal` is true. Costs start at `C::default()` and must not be
+/// negative.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search from `starts`, where `successors` gives the cost of each move, stopping at the
+/// first state for which `is_goal` is true. The path found is a shortest one as long as
+/// `heuristic` never overestimates the cost left to a goal.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = Paths::new();
+    // Best costs and parents found so far, only final once their state is expanded.
+    let mut costs = HashMap::new();
+    let mut parents: HashMap<S, S> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        costs.insert(start.clone(), C::default());
+        queue.push(Candidate {
+            priority: heuristic(&start),
+            cost: C::default(),
+            state: start,
+        });
+    }
+
+    while let Some(Candidate { cost, state, .. }) = queue.pop() {
+        if costs.get(&state).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        paths.distances.insert(state.clone(), cost);
+        if let Some(parent) = parents.get(&state) {
+            paths.parents.insert(state.clone(), parent.clone());
+        }
+        if is_goal(&state) {
+            paths.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if costs.get(&next).is_some_and(|&best| best <= cost) {
+                continue;
+            }
+
+            costs.insert(next.clone(), cost);
+            parents.insert(next.clone(), state.clone());
+            queue.push(Candidate {
+                priority: cost + heuristic(&next),
+                cost,
+                state: next,
+            });
+        }
+    }
+
+    paths
+}
+
+/// Entry of the A* queue, ordered so that the max-heap pops the lowest priority first.
+struct Candidate<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Candidate<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Candidate<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Candidate<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Candidate<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Among equal priorities, the deepest candidate first, as it is closest to a goal.
+        other
+            .priority
+            .cmp(&self.priority)
+            .then(self.cost.cmp(&other.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Weighted graph of the example of the Wikipedia article on Dijkstra's algorithm.
+    fn edges(node: &u8) -> Vec<(u8, u32)> {
+        let edges: &[(u8, u8, u32)] = &[
+            (1, 2, 7),
+            (1, 3, 9),
+            (1, 6, 14),
+            (2, 3, 10),
+            (2, 4, 15),
+            (3, 4, 11),
+            (3, 6, 2),
+            (4, 5, 6),
+            (5, 6, 9),
+        ];
+        edges
+            .iter()
+            .filter_map(|&(a, b, cost)| match *node {
+                n if n == a => Some((b, cost)),
+                n if n == b => Some((a, cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let paths = bfs([1], |&n: &u32| [n * 2, n + 3], |&n| n == 11);
+
+        assert_eq!(Some(&11), paths.goal());
+        assert_eq!(Some(3), paths.goal_distance());
+        assert_eq!(Some(vec![1, 4, 8, 11]), paths.goal_path());
+        assert_eq!(Some(1), paths.distance(&2));
+        assert_eq!(None, paths.path(&1000));
+    }
+
+    #[test]
+    fn test_bfs_exhaustive() {
+        let paths = bfs(
+            [0, 10],
+            |&n: &i32| [n - 1, n + 1].into_iter().filter(|n| (0..=10).contains(n)),
+            |_| false,
+        );
+
+        assert_eq!(None, paths.goal());
+        assert_eq!(11, paths.distances().len());
+        assert_eq!(Some(5), paths.distance(&5));
+        assert_eq!(Some(vec![10, 9, 8]), paths.path(&8));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra([1], edges, |&n| n == 5);
+
+        assert_eq!(Some(20), paths.goal_distance());
+        assert_eq!(Some(vec![1, 3, 6, 5]), paths.goal_path());
+
+        // 6 and 4 were only reached, from 1 and 2, on the way to 3, so their costs weren't final.
+        let paths = dijkstra([1], edges, |&n| n == 3);
+        assert_eq!(Some(9), paths.goal_distance());
+        assert_eq!(3, paths.distances().len());
+        assert_eq!(None, paths.distance(&6));
+        assert_eq!(None, paths.path(&4));
+
+        let paths = dijkstra([1], edges, |_| false);
+        assert_eq!(
+            vec![0, 7, 9, 20, 20, 11],
+            (1..=6)
+                .map(|n| paths.distance(&n).unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walking on a plane from (0, 0) to (5, 3), around a wall at x = 2 with a gap at y = 4.
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x != 2 || y == 4)
+                .map(|next| (next, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (5 - x).abs() + (3 - y).abs();
+        let paths = astar([(0, 0)], successors, heuristic, |&state| state == (5, 3));
+
+        assert_eq!(Some(10), paths.goal_distance());
+        let path = paths.goal_path().unwrap();
+        assert_eq!(11, path.len());
+        assert!(path.contains(&(2, 4)));
+    }
+}